@@ -0,0 +1,114 @@
+use std::fs;
+use std::path::Path;
+
+use super::{Day, Mode, Part, Year};
+
+/// Result of comparison of the calculated answer with the known one
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+/*
+Known-good answers are kept per day in files next to the input folders:
+`./answers/YYYY/day_NN.txt` for real input and `./answers_test/YYYY/day_NN.txt` for test input
+(answers of 2023 may also be kept directly in the folders, without the year).
+The first line of the file is the answer to part 1, the second line is the answer to part 2.
+Empty or absent line means that the answer is not known yet.
+*/
+pub fn get_filepath(year: Year, day: Day, mode: Mode) -> String {
+    let folder = match mode {
+        Mode::Test => "./answers_test",
+        Mode::Real => "./answers",
+    };
+    aoc2023_rust::year_folder(Path::new(folder), year)
+        .join(format!("day_{:0>2}.txt", day))
+        .to_string_lossy()
+        .into_owned()
+}
+
+pub fn load_expected(year: Year, day: Day, part: Part, mode: Mode) -> Option<String> {
+    let text = fs::read_to_string(get_filepath(year, day, mode)).ok()?;
+    parse_expected(&text, part)
+}
+
+pub fn parse_expected(text: &str, part: Part) -> Option<String> {
+    let line_no = match part {
+        Part::One => 0,
+        Part::Two => 1,
+    };
+    text.lines()
+        .nth(line_no)
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+}
+
+/// Save the answer of the part as known-good, keeping the answer of the other part
+pub fn store(year: Year, day: Day, part: Part, answer: &str) -> Result<(), String> {
+    let path = get_filepath(year, day, Mode::Real);
+    let text = fs::read_to_string(&path).unwrap_or_default();
+    Path::new(&path)
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, set_expected(&text, part, answer)))
+        .map_err(|e| format!("could not save answer to '{path}': {e}"))
+}
+
+fn set_expected(text: &str, part: Part, answer: &str) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.len() < 2 {
+        lines.resize(2, "");
+    }
+    lines[part.number() as usize - 1] = answer;
+    lines.join("\n") + "\n"
+}
+
+pub fn verify(expected: Option<String>, actual: &str) -> Verdict {
+    match expected {
+        None => Verdict::Missing,
+        Some(expected) if expected == actual.trim() => Verdict::Pass,
+        Some(expected) => Verdict::Fail { expected },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn parse_expected() {
+        let text = "142\n281\n";
+        assert_eq!(super::parse_expected(text, Part::One), Some("142".into()));
+        assert_eq!(super::parse_expected(text, Part::Two), Some("281".into()));
+        assert_eq!(super::parse_expected("142\n", Part::Two), None);
+        assert_eq!(super::parse_expected("\n281", Part::One), None);
+    }
+    #[test]
+    fn set_expected() {
+        assert_eq!(super::set_expected("", Part::Two, "281"), "\n281\n");
+        assert_eq!(super::set_expected("142\n", Part::Two, "281"), "142\n281\n");
+        assert_eq!(
+            super::set_expected("1\n281\n", Part::One, "142"),
+            "142\n281\n"
+        );
+    }
+    #[test]
+    fn verify() {
+        assert_eq!(super::verify(Some("42".into()), "42"), Verdict::Pass);
+        assert_eq!(
+            super::verify(Some("42".into()), "41"),
+            Verdict::Fail {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(
+            super::verify(Some("42".into()), ""),
+            Verdict::Fail {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(super::verify(None, "42"), Verdict::Missing);
+    }
+}
@@ -0,0 +1,157 @@
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use super::Timings;
+use super::{Day, Mode, Part, Year};
+
+/// Minimal, median and maximal duration of a series of measurements
+#[derive(Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_durations(durations: &[Duration]) -> Stats {
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = match n {
+            0 => Duration::ZERO,
+            n if n % 2 == 1 => sorted[n / 2],
+            n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        };
+        Stats {
+            min: sorted.first().copied().unwrap_or_default(),
+            median,
+            max: sorted.last().copied().unwrap_or_default(),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "min_ns": self.min.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "max_ns": self.max.as_nanos() as u64,
+        })
+    }
+}
+
+/// Benchmark of one part of one day
+pub struct Bench {
+    pub day: Day,
+    pub part: Part,
+    pub answer: String,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Bench {
+    pub fn new(day: Day, part: Part, timings: Timings) -> Bench {
+        Bench {
+            day,
+            part,
+            answer: timings.answer.to_string(),
+            parse: Stats::from_durations(&timings.parse),
+            solve: Stats::from_durations(&timings.solve),
+        }
+    }
+}
+
+pub fn print_table(benches: &[Bench]) {
+    eprintln!(
+        "{:>3} | {:>4} | {:>33} | {:>33}",
+        "Day", "Part", "Parse min / median / max", "Solve min / median / max"
+    );
+    eprintln!("{:-<3}-+-{:-<4}-+-{:-<33}-+-{:-<33}", "", "", "", "");
+    let show = |s: &Stats| {
+        format!(
+            "{:>9} / {:>9} / {:>9}",
+            format!("{:.2?}", s.min),
+            format!("{:.2?}", s.median),
+            format!("{:.2?}", s.max)
+        )
+    };
+    benches.iter().for_each(|b| {
+        eprintln!(
+            "{:>3} | {:>4} | {} | {}",
+            b.day,
+            b.part.number(),
+            show(&b.parse),
+            show(&b.solve)
+        );
+    });
+}
+
+pub fn to_json(benches: &[Bench], year: Year, mode: Mode, repetitions: usize) -> String {
+    let mode = match mode {
+        Mode::Test => "test",
+        Mode::Real => "real",
+    };
+    let results: Vec<Value> = benches
+        .iter()
+        .map(|b| {
+            json!({
+                "day": b.day,
+                "part": b.part.number(),
+                "answer": b.answer,
+                "parse": b.parse.to_json(),
+                "solve": b.solve.to_json(),
+            })
+        })
+        .collect();
+    let report = json!({
+        "year": year,
+        "mode": mode,
+        "repetitions": repetitions,
+        "results": results,
+    });
+    serde_json::to_string_pretty(&report).expect("benchmark is serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn stats_from_durations() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            Stats::from_durations(&[ms(5), ms(1), ms(3)]),
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            }
+        );
+        assert_eq!(
+            Stats::from_durations(&[ms(4), ms(1), ms(2), ms(8)]),
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(8)
+            }
+        );
+    }
+    #[test]
+    fn to_json() {
+        let ms = Duration::from_millis;
+        let bench = Bench {
+            day: 3,
+            part: Part::One,
+            answer: String::from("a\"b\\c\n"),
+            parse: Stats::from_durations(&[ms(1)]),
+            solve: Stats::from_durations(&[ms(2), ms(4)]),
+        };
+        let json: Value =
+            serde_json::from_str(&super::to_json(&[bench], 2023, Mode::Test, 2)).unwrap();
+        assert_eq!(json["mode"], "test");
+        assert_eq!(json["repetitions"], 2);
+        assert_eq!(json["results"][0]["answer"], "a\"b\\c\n");
+        assert_eq!(
+            json["results"][0]["solve"],
+            json!({"min_ns": 2_000_000, "median_ns": 3_000_000, "max_ns": 4_000_000})
+        );
+    }
+}
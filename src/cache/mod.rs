@@ -0,0 +1,197 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use super::{Answer, Day, Part, Year};
+use aoc2023_rust::utils::manifest::checksum;
+
+/*
+Answers calculated by normal runs are cached in `./cache/YYYY/day_NN.txt`, one line per answer:
+part, MD5 of the input, build of the program, kind of the answer (`N` number, `T` text) and the answer,
+separated by tabs. The kind keeps a text answer such as `007` from coming back as a number.
+An answer is taken from the cache only when it was calculated from the same input by the same build,
+so changes of a solution never give a stale answer. Build is identified by MD5 of the executable.
+Cache is opt-in: it is used when setting `cache` is on (`AOC_CACHE=1` or `cache = on` in `aoc.conf`).
+*/
+pub const FOLDER: &str = "./cache";
+
+/// Cache is enabled in the settings
+pub fn enabled() -> bool {
+    aoc2023_rust::utils::setting("cache")
+        .is_some_and(|value| matches!(value.to_lowercase().as_str(), "1" | "true" | "on" | "yes"))
+}
+
+/// Identifier of the running build, the version is used when the executable can't be read
+fn build() -> &'static str {
+    static BUILD: OnceLock<String> = OnceLock::new();
+    BUILD.get_or_init(|| {
+        std::env::current_exe()
+            .and_then(fs::read)
+            .map(|exe| checksum(&exe))
+            .unwrap_or_else(|_| String::from(env!("CARGO_PKG_VERSION")))
+    })
+}
+
+/// Key of the cached answer
+pub struct Key<'a> {
+    pub year: Year,
+    pub day: Day,
+    pub part: Part,
+    pub input: &'a str,
+}
+
+impl Key<'_> {
+    fn path(&self, root: &Path) -> PathBuf {
+        root.join(self.year.to_string())
+            .join(format!("day_{:0>2}.txt", self.day))
+    }
+
+    fn prefix(&self, build: &str) -> String {
+        format!(
+            "{}\t{}\t{build}\t",
+            self.part.number(),
+            checksum(self.input.as_bytes())
+        )
+    }
+}
+
+pub fn load(key: &Key) -> Option<Answer> {
+    load_in(Path::new(FOLDER), build(), key)
+}
+
+fn load_in(root: &Path, build: &str, key: &Key) -> Option<Answer> {
+    let text = fs::read_to_string(key.path(root)).ok()?;
+    let prefix = key.prefix(build);
+    let cached = text.lines().find_map(|line| line.strip_prefix(&prefix))?;
+    match cached.split_once('\t')? {
+        ("N", number) => number.parse().ok().map(Answer::Number),
+        ("T", text) => Some(Answer::Text(String::from(text))),
+        _ => None,
+    }
+}
+
+/// Save the answer, parts that are not solved are not cached
+pub fn store(key: &Key, answer: &Answer) -> Result<(), String> {
+    // both parts of a day share the file, answers solved in parallel are stored one at a time
+    static STORING: Mutex<()> = Mutex::new(());
+    if !answer.is_solved() {
+        return Ok(());
+    }
+    let _storing = STORING.lock().unwrap_or_else(PoisonError::into_inner);
+    store_in(Path::new(FOLDER), build(), key, answer)
+}
+
+/// Save the answer, dropping answers of the part calculated by other builds or from the same input
+fn store_in(root: &Path, build: &str, key: &Key, answer: &Answer) -> Result<(), String> {
+    let answer = match answer {
+        Answer::Number(number) => format!("N\t{number}"),
+        // answers are kept one per line
+        Answer::Text(text) if !text.contains(['\n', '\t']) => format!("T\t{text}"),
+        _ => return Ok(()),
+    };
+    let path = key.path(root);
+    let text = fs::read_to_string(&path).unwrap_or_default();
+    let prefix = key.prefix(build);
+    let part = format!("{}\t", key.part.number());
+    let mut lines: Vec<&str> = text
+        .lines()
+        .filter(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            !line.starts_with(&part) || (fields.len() == 5 && fields[2] == build)
+        })
+        .filter(|line| !line.starts_with(&prefix))
+        .collect();
+    let line = format!("{prefix}{answer}");
+    lines.push(&line);
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, lines.join("\n") + "\n"))
+        .map_err(|e| format!("could not cache answer to '{}': {e}", path.display()))
+}
+
+/// Remove all cached answers
+pub fn clear() -> Result<(), String> {
+    clear_in(Path::new(FOLDER))
+}
+
+fn clear_in(root: &Path) -> Result<(), String> {
+    match fs::remove_dir_all(root) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(format!("could not clear cache '{}': {e}", root.display()))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn store_in() {
+        let root = std::env::temp_dir().join(format!("aoc_cache_{}", std::process::id()));
+        let key = |part, input| Key {
+            year: 2023,
+            day: 22,
+            part,
+            input,
+        };
+        let number = |n| Some(Answer::Number(n));
+        assert_eq!(load_in(&root, "b1", &key(Part::One, "1,0,1~1,2,1\n")), None);
+        super::store_in(
+            &root,
+            "b1",
+            &key(Part::One, "1,0,1~1,2,1\n"),
+            &Answer::Number(5),
+        )
+        .unwrap();
+        super::store_in(
+            &root,
+            "b1",
+            &key(Part::Two, "1,0,1~1,2,1\n"),
+            &Answer::Number(7),
+        )
+        .unwrap();
+        super::store_in(
+            &root,
+            "b1",
+            &key(Part::One, "0,0,1~0,0,2\n"),
+            &Answer::Number(1),
+        )
+        .unwrap();
+        assert_eq!(
+            load_in(&root, "b1", &key(Part::One, "1,0,1~1,2,1\n")),
+            number(5)
+        );
+        assert_eq!(
+            load_in(&root, "b1", &key(Part::One, "0,0,1~0,0,2\n")),
+            number(1)
+        );
+        assert_eq!(load_in(&root, "b2", &key(Part::One, "1,0,1~1,2,1\n")), None);
+
+        // answers of older builds are dropped
+        super::store_in(
+            &root,
+            "b2",
+            &key(Part::One, "1,0,1~1,2,1\n"),
+            &Answer::Number(5),
+        )
+        .unwrap();
+        let text = fs::read_to_string(root.join("2023/day_22.txt")).unwrap();
+        assert_eq!(text.lines().count(), 2);
+        assert_eq!(
+            load_in(&root, "b1", &key(Part::Two, "1,0,1~1,2,1\n")),
+            number(7)
+        );
+
+        // text answers that look like numbers stay texts
+        for text in ["007", "+5", "AHK"] {
+            let answer = Answer::Text(String::from(text));
+            super::store_in(&root, "b2", &key(Part::Two, text), &answer).unwrap();
+            assert_eq!(load_in(&root, "b2", &key(Part::Two, text)), Some(answer));
+        }
+
+        clear_in(&root).unwrap();
+        assert!(!root.exists());
+        clear_in(&root).unwrap();
+    }
+}
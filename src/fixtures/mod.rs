@@ -0,0 +1,112 @@
+use std::fs;
+use std::path::Path;
+
+use super::{answers, Day, Part, Year};
+
+/*
+Example inputs of a day are kept in the year folders of the test inputs:
+`./input_test/YYYY/day_NN.txt` is the default one and `./input_test/YYYY/day_NN/<name>.txt` are the named ones.
+Fixtures of 2023 may also be kept directly in the test folders, without the year.
+Expected answers of a fixture are stored under the same name in `./answers_test/YYYY`,
+in the same format as the known answers: first line for part 1, second line for part 2.
+When a fixture has the answers file but no answer for some part, it is not run for that part,
+which is the case for examples given for one part only (e.g. spelled out digits of day 1).
+*/
+pub const DEFAULT_NAME: &str = "default";
+
+/// Example input of a day with its expected answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub name: String,
+    pub input_path: String,
+    answers: Option<String>,
+}
+
+impl Fixture {
+    fn load(name: &str, input_path: String, answers_path: String) -> Fixture {
+        Fixture {
+            name: String::from(name),
+            input_path,
+            answers: fs::read_to_string(answers_path).ok(),
+        }
+    }
+
+    /// Fixture without answers is run for both parts, to print what was calculated
+    pub fn applies_to(&self, part: Part) -> bool {
+        match &self.answers {
+            None => true,
+            Some(text) => answers::parse_expected(text, part).is_some(),
+        }
+    }
+
+    pub fn expected(&self, part: Part) -> Option<String> {
+        self.answers
+            .as_deref()
+            .and_then(|text| answers::parse_expected(text, part))
+    }
+}
+
+pub fn list(year: Year, day: Day) -> Vec<Fixture> {
+    list_in(Path::new("."), year, day)
+}
+
+fn list_in(root: &Path, year: Year, day: Day) -> Vec<Fixture> {
+    let inputs = aoc2023_rust::year_folder(&root.join("input_test"), year);
+    let answers = aoc2023_rust::year_folder(&root.join("answers_test"), year);
+    let file_name = format!("day_{:0>2}", day);
+    let path_of = |p: &Path| p.to_string_lossy().into_owned();
+    let default = inputs.join(format!("{file_name}.txt"));
+    let mut named: Vec<Fixture> = fs::read_dir(inputs.join(&file_name))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|e| e == "txt"))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_owned();
+            let answers_path = answers.join(&file_name).join(format!("{name}.txt"));
+            Some(Fixture::load(&name, path_of(&path), path_of(&answers_path)))
+        })
+        .collect();
+    named.sort_by(|a, b| a.name.cmp(&b.name));
+    let default = default.is_file().then(|| {
+        let answers_path = answers.join(format!("{file_name}.txt"));
+        Fixture::load(DEFAULT_NAME, path_of(&default), path_of(&answers_path))
+    });
+    default.into_iter().chain(named).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn list_in() {
+        let root = std::env::temp_dir().join(format!("aoc_fixtures_{}", std::process::id()));
+        let write = |path: &str, text: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        };
+        write("input_test/day_01.txt", "1abc2\n");
+        write("answers_test/day_01.txt", "12\n");
+        write("input_test/day_01/spelled.txt", "two1nine\n");
+        write("answers_test/day_01/spelled.txt", "\n29\n");
+        write("input_test/day_01/another.txt", "3\n");
+        write("input_test/day_01/notes.md", "ignored");
+
+        let fixtures = super::list_in(&root, 2023, 1);
+        let names: Vec<&str> = fixtures.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, [DEFAULT_NAME, "another", "spelled"]);
+        let (default, another, spelled) = (&fixtures[0], &fixtures[1], &fixtures[2]);
+        assert!(default.applies_to(Part::One) && !default.applies_to(Part::Two));
+        assert_eq!(default.expected(Part::One), Some("12".into()));
+        assert!(another.applies_to(Part::One) && another.applies_to(Part::Two));
+        assert_eq!(another.expected(Part::Two), None);
+        assert!(!spelled.applies_to(Part::One) && spelled.applies_to(Part::Two));
+        assert_eq!(spelled.expected(Part::Two), Some("29".into()));
+        assert!(super::list_in(&root, 2023, 2).is_empty());
+        assert!(super::list_in(&root, 2022, 1).is_empty());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
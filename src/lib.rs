@@ -0,0 +1,342 @@
+//! Solutions of Advent of Code puzzles, 2023 and the years added later.
+//!
+//! [`solve`] calculates the answer to one part of a day puzzle from the text of the puzzle input,
+//! [`bench`] measures how long parsing and solving takes. Both leave reading of the input to the caller.
+//! Solutions of every year are listed in [`YEARS`].
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+pub mod logging;
+pub mod solution;
+pub mod utils;
+
+pub use solution::{parse_number, Entry, ParseError, Timings, YEARS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+pub type Year = u16;
+
+/// Year of the puzzles this crate was started with
+pub const DEFAULT_YEAR: Year = 2023;
+
+pub type Day = u8;
+
+/// Days of the calendar that have a solution
+pub const ALL_DAYS: RangeInclusive<Day> = 1..=25;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Mode {
+    Test,
+    Real,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum Logging {
+    Error,
+    Warning,
+    Info,
+    Debug,
+    Trace,
+}
+
+/// Answer to a part of day puzzle, shown the way it is submitted to the site
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The solution gives no answer to the part
+    Unsolved,
+    /// The solution has not found the answer for the input, with the reason
+    Failed(String),
+}
+
+impl Answer {
+    /// There is an answer to submit or to compare with the known one
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Answer::Number(_) | Answer::Text(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Unsolved => write!(f, "<not solved>"),
+            Answer::Failed(reason) => write!(f, "<failed: {reason}>"),
+        }
+    }
+}
+
+macro_rules! number_answer {
+    ($($number:ty),*) => {
+        $(impl From<$number> for Answer {
+            fn from(number: $number) -> Self {
+                Answer::Number(number as i128)
+            }
+        })*
+    };
+}
+
+number_answer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+/// `None` is the answer of a part the solution does not solve
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+/// Reason why a puzzle could not be solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// There is no solution for the day of the year
+    UnknownDay(Year, Day),
+    /// The day is solved, but not this part of it
+    UnsolvedPart(Day, Part),
+    /// Puzzle input is malformed
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownDay(year, day) => write!(f, "day {day} of {year} is not solved"),
+            Error::UnsolvedPart(day, Part::One) => write!(f, "part 1 of day {day} is not solved"),
+            Error::UnsolvedPart(day, Part::Two) => write!(f, "part 2 of day {day} is not solved"),
+            Error::Parse(e) => write!(f, "could not parse input: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::UnknownDay(_, _) | Error::UnsolvedPart(_, _) => None,
+            Error::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+/// Registry entry of the day with the given part solved
+fn find(year: Year, day: Day, part: Part) -> Result<&'static Entry, Error> {
+    let entry = solution::find(year, day).ok_or(Error::UnknownDay(year, day))?;
+    if entry.implements(part) {
+        Ok(entry)
+    } else {
+        Err(Error::UnsolvedPart(day, part))
+    }
+}
+
+/// Solve given part of day puzzle of 2023 for the puzzle input
+pub fn solve(day: Day, part: Part, input: &str) -> Result<Answer, Error> {
+    solve_year(DEFAULT_YEAR, day, part, input)
+}
+
+/// Solve given part of day puzzle of the year for the puzzle input
+pub fn solve_year(year: Year, day: Day, part: Part, input: &str) -> Result<Answer, Error> {
+    Ok((find(year, day, part)?.run)(part, String::from(input))?)
+}
+
+/// Part of a day puzzle made ready by `solve_parts` to be solved on its own, e.g. in a thread of its own
+pub type Job = Box<dyn FnOnce() -> Result<Timings, Error> + Send>;
+
+/// Jobs solving given parts of day puzzle of the year independently of each other,
+/// the input is parsed here once when the parts solve the same problem.
+/// A job gives the answer of its part with durations of parsing and solving,
+/// or the error of the part when it could not be made ready.
+pub fn solve_parts(year: Year, day: Day, parts: &[Part], input: &str) -> Vec<(Part, Job)> {
+    let failed = |part, error: Error| -> (Part, Job) { (part, Box::new(move || Err(error))) };
+    let Some(entry) = solution::find(year, day) else {
+        return parts
+            .iter()
+            .map(|&part| failed(part, Error::UnknownDay(year, day)))
+            .collect();
+    };
+    let (solved, unsolved): (Vec<Part>, Vec<Part>) =
+        parts.iter().partition(|&&part| entry.implements(part));
+    let mut jobs: Vec<(Part, Job)> = unsolved
+        .into_iter()
+        .map(|part| failed(part, Error::UnsolvedPart(day, part)))
+        .collect();
+    match (entry.jobs)(&solved, String::from(input)) {
+        Ok(ready) => jobs.extend(ready.into_iter().map(|(part, job)| -> (Part, Job) {
+            (part, Box::new(move || job().map_err(Error::from)))
+        })),
+        // the problem is shared, so every part gets its error
+        Err(e) => jobs.extend(
+            solved
+                .into_iter()
+                .map(|part| failed(part, e.clone().into())),
+        ),
+    }
+    jobs
+}
+
+/// Parse and solve given part of day puzzle several times, measuring each step
+pub fn bench(
+    year: Year,
+    day: Day,
+    part: Part,
+    input: &str,
+    repetitions: usize,
+) -> Result<Timings, Error> {
+    Ok((find(year, day, part)?.bench)(
+        part,
+        String::from(input),
+        repetitions,
+    )?)
+}
+
+/// Folder with data of the year inside of `folder`, e.g. `./input/2023`.
+/// Data of 2023 may also be kept directly in `folder`, as it was before other years were added.
+pub fn year_folder(folder: &Path, year: Year) -> PathBuf {
+    let path = folder.join(year.to_string());
+    if year == DEFAULT_YEAR && !path.is_dir() {
+        folder.to_path_buf()
+    } else {
+        path
+    }
+}
+
+/// Path of the file with puzzle input of the day, relative to working directory
+pub fn input_filepath(year: Year, day: Day, mode: Mode) -> String {
+    let folder = match mode {
+        Mode::Test => "./input_test",
+        Mode::Real => "./input",
+    };
+    year_folder(Path::new(folder), year)
+        .join(format!("day_{:0>2}.txt", day))
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn solve() {
+        assert_eq!(
+            super::solve(1, Part::One, "1abc2\na1b2c3d\n"),
+            Ok(Answer::Number(25))
+        );
+        assert_eq!(
+            super::solve(26, Part::One, ""),
+            Err(Error::UnknownDay(2023, 26))
+        );
+        assert_eq!(
+            super::solve_year(2015, 1, Part::One, ""),
+            Err(Error::UnknownDay(2015, 1))
+        );
+        assert_eq!(
+            super::solve(25, Part::Two, ""),
+            Err(Error::UnsolvedPart(25, Part::Two))
+        );
+        assert!(matches!(
+            super::solve(1, Part::One, "abc\n"),
+            Err(Error::Parse(ParseError { line: 1, .. }))
+        ));
+    }
+    #[test]
+    fn solve_parts() {
+        let solve = |day, input| {
+            super::solve_parts(DEFAULT_YEAR, day, &[Part::One, Part::Two], input)
+                .into_iter()
+                .map(|(part, job)| (part, job()))
+                .collect::<Vec<_>>()
+        };
+        let solved = solve(1, "1abc2\n");
+        assert!(matches!(
+            solved[..],
+            [
+                (
+                    Part::One,
+                    Ok(Timings {
+                        answer: Answer::Number(12),
+                        ..
+                    })
+                ),
+                (
+                    Part::Two,
+                    Ok(Timings {
+                        answer: Answer::Number(12),
+                        ..
+                    })
+                )
+            ]
+        ));
+        // day 1 reads the input of part 2 differently, so each part is parsed on its own
+        assert!(solved
+            .iter()
+            .all(|(_, t)| t.as_ref().unwrap().parse.len() == 1));
+        // the input of both parts is parsed once, so both of them get its error
+        let solved = solve(2, "x\n");
+        assert!(matches!(
+            solved[..],
+            [
+                (Part::One, Err(Error::Parse(_))),
+                (Part::Two, Err(Error::Parse(_)))
+            ]
+        ));
+        // parsing of the shared problem is timed for the first part only
+        let solved = solve(9, "0 3 6\n");
+        let parsed: Vec<usize> = solved
+            .iter()
+            .map(|(_, t)| t.as_ref().unwrap().parse.len())
+            .collect();
+        assert_eq!(parsed, [1, 0]);
+        let solved = solve(26, "");
+        assert!(solved
+            .iter()
+            .all(|(_, t)| matches!(t, Err(Error::UnknownDay(2023, 26)))));
+    }
+    #[test]
+    fn answer() {
+        assert_eq!(Answer::from(Some(12_u32)).to_string(), "12");
+        assert_eq!(Answer::from(-3_i64), Answer::Number(-3));
+        assert_eq!(Answer::from(None::<usize>), Answer::Unsolved);
+        assert!(!Answer::Unsolved.is_solved());
+        assert_eq!(
+            Answer::Failed(String::from("no path")).to_string(),
+            "<failed: no path>"
+        );
+    }
+    #[test]
+    fn year_folder() {
+        let root = std::env::temp_dir().join(format!("aoc_years_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("2022")).unwrap();
+        assert_eq!(super::year_folder(&root, 2023), root);
+        assert_eq!(super::year_folder(&root, 2022), root.join("2022"));
+        assert_eq!(super::year_folder(&root, 2021), root.join("2021"));
+        std::fs::create_dir_all(root.join("2023")).unwrap();
+        assert_eq!(super::year_folder(&root, 2023), root.join("2023"));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
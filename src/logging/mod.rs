@@ -0,0 +1,57 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use super::Logging;
+
+/*
+Diagnostic output of solutions goes to standard error, so that answers on standard output stay clean.
+Level is set once by the caller of the solutions, messages above the level are not printed.
+*/
+static LEVEL: AtomicU8 = AtomicU8::new(Logging::Warning as u8);
+
+pub fn set_level(level: Logging) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Logging) -> bool {
+    level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Print message to standard error when given level of logging is enabled
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::logging::enabled($level) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Print message to standard error at `Debug` level
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        $crate::logging::log!($crate::Logging::Debug, $($arg)*)
+    };
+}
+
+/// Print message to standard error at `Trace` level
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        $crate::logging::log!($crate::Logging::Trace, $($arg)*)
+    };
+}
+
+pub(crate) use {debug, log, trace};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn enabled() {
+        set_level(Logging::Info);
+        assert!(super::enabled(Logging::Error));
+        assert!(super::enabled(Logging::Info));
+        assert!(!super::enabled(Logging::Debug));
+        set_level(Logging::Trace);
+        assert!(super::enabled(Logging::Trace));
+        set_level(Logging::Warning);
+    }
+}
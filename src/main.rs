@@ -1,15 +1,43 @@
-use clap::Parser;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+use rayon::prelude::*;
 use std::io;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
 
-const YEAR: i32 = 2023;
-
-/// Advent of Code 2023 launcher
+/// Advent of Code launcher
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, subcommand_negates_reqs = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Year of the puzzles to solve
+    #[arg(short, long, default_value_t = DEFAULT_YEAR, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Year,
     /// Day number to solve
-    #[arg(short, long)]
-    day: u8,
+    #[arg(
+        short,
+        long,
+        value_parser = clap::value_parser!(u8).range(1..=25),
+        required_unless_present_any = ["all", "days", "list", "verify_inputs"],
+        conflicts_with_all = ["all", "days"]
+    )]
+    day: Option<u8>,
+    /// Solve every day of the calendar one after another
+    #[arg(short, long, conflicts_with = "days")]
+    all: bool,
+    /// Range of days to solve one after another, e.g. 3..=12, 3..12 or 3-12
+    #[arg(long, value_parser = parse_days)]
+    days: Option<RangeInclusive<Day>>,
+    /// List solved days with their titles and parts
+    #[arg(short, long, conflicts_with_all = ["day", "all", "days"])]
+    list: bool,
+    /// Part of the puzzle to solve
+    #[arg(short, long, value_enum, default_value_t = Parts::Both)]
+    part: Parts,
     /// get input data for day problem from site
     /*
     For this option to work, SESSION file is required. Session file must contain the 'session' variable of cookie for advent of code site.
@@ -18,93 +46,1122 @@ struct Cli {
     */
     #[arg(short, long)]
     get_input: bool,
+    /// Get puzzle description from site as Markdown and save its examples as test inputs
+    #[arg(long, conflicts_with = "get_input")]
+    get_puzzle: bool,
+    /// Wait until the day is unlocked, then download its input and solve it
+    #[arg(
+        long,
+        requires = "day",
+        conflicts_with_all = ["all", "days", "get_input", "get_puzzle", "stdin", "test"]
+    )]
+    wait: bool,
+    /// Check downloaded inputs of selected days against the checksums recorded at download
+    #[arg(long, conflicts_with_all = ["get_input", "get_puzzle", "stdin", "test", "submit"])]
+    verify_inputs: bool,
     /// Read data from standard input
     #[arg(short, long)]
     stdin: bool,
-    /// Solve problem in test mode
+    /// Solve every example input of selected days and compare answers with the expected ones
     #[arg(short, long)]
     test: bool,
-    /// Define the level of logging output when running the solution
+    /// Compare answers with the known-good ones stored in answers folder, fail on mismatch
     #[arg(short, long)]
+    check: bool,
+    /// Measure parsing and solving time over N repetitions and print results as JSON
+    #[arg(short, long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+    /// Send the answer of the day part to the site and record the outcome
+    #[arg(long, requires = "day", conflicts_with_all = ["all", "days", "test", "check", "bench"])]
+    submit: bool,
+    /// Solve again instead of taking the answers from the cache
+    #[arg(long)]
+    no_cache: bool,
+    /// Print answers as text, or as JSON or CSV records with status, durations and input of every part
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = report::Format::Text,
+        conflicts_with_all = ["list", "test", "check", "bench", "submit"]
+    )]
+    format: report::Format,
+    /// Number of days and parts solved at the same time, all processors by default.
+    /// Solutions that are parallel themselves use all processors anyway
+    #[arg(
+        short,
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u16).range(1..),
+        conflicts_with_all = ["list", "test", "bench", "submit"]
+    )]
+    jobs: Option<u16>,
+    /// Give up a part that is not parsed and solved in SECONDS and go on with the others.
+    /// The part keeps running in the background until the launcher exits
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        value_parser = clap::value_parser!(u64).range(1..),
+        conflicts_with_all = ["list", "test", "bench", "submit"]
+    )]
+    timeout: Option<u64>,
+    /// Print debug output of the solution to standard error, same as `--log-level debug`
+    #[arg(long, conflicts_with = "log_level")]
     debug: bool,
+    /// Define the level of logging output when running the solution
+    #[arg(long, value_enum, default_value_t = Logging::Warning)]
+    log_level: Logging,
+}
+
+/// Commands that do not solve puzzles
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Manage answers cached by earlier runs
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Write encrypted copies of the inputs (`input/**/day_NN.txt.enc`) with the key from AOC_INPUT_KEY
+    EncryptInputs,
+    /// Write plain inputs from their encrypted copies, existing plain inputs are kept
+    DecryptInputs,
+    /// Generate the module of a new day from the template and register it
+    New {
+        /// Day number of the puzzle
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Day,
+        /// Year of the puzzle
+        #[arg(short, long, default_value_t = DEFAULT_YEAR, value_parser = clap::value_parser!(u16).range(2015..))]
+        year: Year,
+    },
+    /// Show private leaderboard of the year with stars of its members
+    Leaderboard {
+        /// Id of the leaderboard, the number at the end of its address
+        #[arg(long)]
+        id: u64,
+        /// Year of the leaderboard
+        #[arg(short, long, default_value_t = DEFAULT_YEAR, value_parser = clap::value_parser!(u16).range(2015..))]
+        year: Year,
+        /// Show times of the stars of the day, compared with the local submissions
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<Day>,
+        /// Fetch the leaderboard even if the saved one is fresh
+        #[arg(long)]
+        refresh: bool,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum CacheAction {
+    /// Remove all cached answers
+    Clear,
 }
 
-use solution::Solution;
+use aoc2023_rust::utils::{encryption, manifest, submit};
+use aoc2023_rust::{
+    logging, utils, Answer, Day, Error, Logging, Mode, Part, Timings, Year, ALL_DAYS, DEFAULT_YEAR,
+};
 
-mod solution;
-mod utils;
+mod answers;
+mod bench;
+mod cache;
+mod fixtures;
+mod report;
+mod scaffold;
 
-pub enum Part {
+/// Selection of puzzle parts given on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Parts {
+    #[value(name = "1")]
     One,
+    #[value(name = "2")]
     Two,
+    Both,
 }
 
-type Day = u8;
-
-#[derive(Debug, Clone, Copy)]
-pub enum Mode {
-    Test,
-    Real,
+impl Parts {
+    fn parts(self) -> &'static [Part] {
+        match self {
+            Parts::One => &[Part::One],
+            Parts::Two => &[Part::Two],
+            Parts::Both => &[Part::One, Part::Two],
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Logging {
-    Error,
-    Warning,
-    Info,
-    Debug,
-    Trace,
-}
+/// Exit code used when puzzle input could not be parsed, distinct from 2 of command line errors
+const EXIT_PARSE_ERROR: i32 = 4;
+/// Exit code used when a solution panicked or ran out of time
+const EXIT_SOLUTION_FAILED: i32 = 3;
 
 fn main() {
     // cli interface
     let args = Cli::parse();
-    let day = args.day;
+    match args.command {
+        Some(Command::Cache {
+            action: CacheAction::Clear,
+        }) => {
+            if let Err(e) = cache::clear() {
+                eprintln!("error: {e}");
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(Command::EncryptInputs) => std::process::exit(crypt_inputs(true)),
+        Some(Command::DecryptInputs) => std::process::exit(crypt_inputs(false)),
+        Some(Command::New { day, year }) => match scaffold::create(Path::new("."), year, day) {
+            Ok(files) => {
+                files.iter().for_each(|path| println!("{}", path.display()));
+                return;
+            }
+            Err(e) => {
+                eprintln!("error: {e}");
+                std::process::exit(1);
+            }
+        },
+        Some(Command::Leaderboard {
+            id,
+            year,
+            day,
+            refresh,
+        }) => {
+            std::process::exit(show_leaderboard(year, id, day, refresh));
+        }
+        None => (),
+    }
+    let year = args.year;
     let mode = if args.test { Mode::Test } else { Mode::Real };
     let dlin = args.get_input;
+    let use_cache = !args.no_cache && cache::enabled();
+    logging::set_level(if args.debug {
+        Logging::Debug
+    } else {
+        args.log_level
+    });
     // standard input
     let stdin: Option<String> = if args.stdin {
         io::read_to_string(io::stdin()).ok()
     } else {
         None
     };
-    if dlin {
-        utils::download_input(YEAR, day as u32).unwrap();
+    let parts = args.part.parts();
+    // a part that is not solved is an error only when it was the one asked for
+    let skip_unsolved = args.part == Parts::Both;
+    let days = match args.day {
+        Some(day) => day..=day,
+        None => args.days.unwrap_or(ALL_DAYS),
+    };
+    // parts without solution are skipped, unless the day was asked for explicitly
+    // or the whole year has no solutions, which is reported when solving
+    let unsolved_year = aoc2023_rust::solution::registry(year).is_none();
+    let tasks: Vec<(Day, Part)> = days
+        .clone()
+        .flat_map(|day| parts.iter().map(move |&part| (day, part)))
+        .filter(|&(day, part)| args.day.is_some() || unsolved_year || is_solved(year, day, part))
+        .collect();
+    if args.wait {
+        if let Err(e) = wait_and_download(year, *days.start()) {
+            eprintln!("Day {}: {e}", days.start());
+            std::process::exit(1);
+        }
+    }
+    let solving = !(args.list || args.get_puzzle || dlin || args.verify_inputs);
+    if let (true, Mode::Real, None) = (solving, mode, &stdin) {
+        warn_damaged_inputs(year, &tasks);
+    }
+    if args.list {
+        print_list(year);
+    } else if args.get_puzzle {
+        days.for_each(|day| match utils::puzzle::download_puzzle(year, day) {
+            Ok(downloaded) => {
+                println!(
+                    "Day {day}: description saved to {}",
+                    downloaded.description.display()
+                );
+                downloaded
+                    .examples
+                    .iter()
+                    .for_each(|path| println!("Day {day}: example saved to {}", path.display()));
+            }
+            Err(e) => eprintln!("Day {day}: {e}"),
+        });
+    } else if dlin {
+        days.for_each(|day| match utils::download_input(year, day) {
+            Ok(download) => {
+                let path = match download {
+                    utils::Download::Present(path) => {
+                        println!("File has already been downloaded earlier. Content:");
+                        path
+                    }
+                    utils::Download::Saved(path) => {
+                        println!("File has been downloaded. Content:");
+                        path
+                    }
+                };
+                match std::fs::read_to_string(&path) {
+                    Ok(content) => println!("{content}"),
+                    Err(e) => eprintln!("Day {day}: could not read '{}': {e}", path.display()),
+                }
+            }
+            Err(e) => eprintln!("Day {day}: {e}"),
+        });
+    } else if args.verify_inputs {
+        std::process::exit(verify_inputs(year, days));
+    } else if let Some(repetitions) = args.bench {
+        let repetitions = repetitions as usize;
+        let timings: Vec<_> = tasks
+            .into_iter()
+            .filter(|&(day, part)| {
+                !skip_unsolved
+                    || is_solved(year, day, part)
+                    || aoc2023_rust::solution::find(year, day).is_none()
+            })
+            .map(|(day, part)| {
+                let input =
+                    load_input(year, day, mode, &stdin).map_err(|e| eprintln!("Day {day}: {e}"))?;
+                aoc2023_rust::bench(year, day, part, &input, repetitions)
+                    .map(|timings| bench::Bench::new(day, part, timings))
+                    .map_err(|e| report_input_error(year, day, mode, &stdin, &e))
+            })
+            .collect();
+        let failed = timings.iter().any(|t| t.is_err());
+        let benches: Vec<bench::Bench> = timings.into_iter().flatten().collect();
+        bench::print_table(&benches);
+        println!("{}", bench::to_json(&benches, year, mode, repetitions));
+        if failed {
+            std::process::exit(EXIT_PARSE_ERROR);
+        }
+    } else if args.submit {
+        let &[part] = parts else {
+            Cli::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    "--submit needs a single part, e.g. --part 1",
+                )
+                .exit();
+        };
+        std::process::exit(submit_answer(
+            year,
+            days.start().to_owned(),
+            part,
+            &stdin,
+            use_cache,
+        ));
+    } else if let (Mode::Test, None) = (mode, &stdin) {
+        std::process::exit(run_fixtures(year, days, parts));
+    } else {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(args.jobs.map_or(0, usize::from))
+            .build()
+            .unwrap_or_else(|e| panic!("Couldn't start worker threads: {e}"));
+        let timeout = args.timeout.map(Duration::from_secs);
+        let start = Instant::now();
+        // results are collected in the order of tasks, whichever of them is solved first
+        let mut days: Vec<(Day, Vec<Part>)> = vec![];
+        for (day, part) in tasks {
+            match days.last_mut() {
+                Some((last, parts)) if *last == day => parts.push(part),
+                _ => days.push((day, vec![part])),
+            }
+        }
+        let results: Vec<RunResult> = pool.install(|| {
+            days.into_par_iter()
+                .flat_map_iter(|(day, parts)| {
+                    run_day(year, day, &parts, mode, &stdin, use_cache, timeout)
+                })
+                .collect()
+        });
+        let wall_clock = start.elapsed();
+        // both parts usually share the parser and always the input, report the same error only once
+        let mut reported: Option<(Day, &Error)> = None;
+        let mut unreadable: Option<Day> = None;
+        for r in &results {
+            match &r.answer {
+                Err(Failure::Input(reason)) if unreadable != Some(r.day) => {
+                    eprintln!("Day {}: {reason}", r.day);
+                    unreadable = Some(r.day);
+                }
+                Err(Failure::Solve(Error::UnsolvedPart(..))) if skip_unsolved => (),
+                Err(Failure::Solve(e)) if reported != Some((r.day, e)) => {
+                    report_input_error(year, r.day, mode, &stdin, e);
+                    reported = Some((r.day, e));
+                }
+                _ => (),
+            }
+        }
+        if args.format != report::Format::Text {
+            let records: Vec<report::Record> = results
+                .iter()
+                .map(|r| r.record(year, mode, &stdin))
+                .collect();
+            match args.format {
+                report::Format::Json => println!("{}", report::to_json(&records, year, mode)),
+                _ => print!("{}", report::to_csv(&records)),
+            }
+        } else if args.check {
+            if !print_check(&results, year, mode) {
+                std::process::exit(1);
+            }
+        } else if args.day.is_some() {
+            results
+                .iter()
+                .for_each(|r| println!("The part {} answer is: {}", r.part.number(), r.text()));
+        } else {
+            print_summary(&results, wall_clock);
+        }
+        // parts the registry doesn't implement are shown as not solved, not as failures,
+        // unless a single part was asked for
+        let failures: Vec<&Failure> = results
+            .iter()
+            .filter_map(|r| r.answer.as_ref().err())
+            .filter(|f| !(skip_unsolved && matches!(f, Failure::Solve(Error::UnsolvedPart(..)))))
+            .collect();
+        if failures
+            .iter()
+            .any(|f| matches!(f, Failure::Input(_) | Failure::Solve(_)))
+        {
+            std::process::exit(EXIT_PARSE_ERROR);
+        }
+        if !failures.is_empty() {
+            std::process::exit(EXIT_SOLUTION_FAILED);
+        }
+    }
+}
+
+/// Solve the parts of the day, taking their answers from the cache when it is used.
+/// Time of a part is counted from the answer of the previous one, so that reading and parsing
+/// of the input shared by the parts is counted once.
+fn run_day(
+    year: Year,
+    day: Day,
+    parts: &[Part],
+    mode: Mode,
+    stdin: &Option<String>,
+    use_cache: bool,
+    timeout: Option<Duration>,
+) -> Vec<RunResult> {
+    let input = match load_input(year, day, mode, stdin) {
+        Ok(input) => input,
+        Err(e) => {
+            let failure = Failure::Input(e.to_string());
+            return parts
+                .iter()
+                .map(|&part| RunResult {
+                    day,
+                    part,
+                    answer: Err(failure.clone()),
+                    elapsed: Duration::ZERO,
+                    parse: None,
+                    solve: None,
+                    input_md5: String::new(),
+                })
+                .collect();
+        }
+    };
+    let input_md5 = manifest::checksum(input.as_bytes());
+    let key = |part| cache::Key {
+        year,
+        day,
+        part,
+        input: &input,
+    };
+    let result = |part, answer, elapsed, parse, solve| RunResult {
+        day,
+        part,
+        answer,
+        elapsed,
+        parse,
+        solve,
+        input_md5: input_md5.clone(),
+    };
+    let mut results = vec![];
+    let mut unsolved = vec![];
+    for &part in parts {
+        let start = Instant::now();
+        match use_cache.then(|| cache::load(&key(part))).flatten() {
+            Some(answer) => results.push(result(part, Ok(answer), start.elapsed(), None, None)),
+            None => unsolved.push(part),
+        }
+    }
+    if !unsolved.is_empty() {
+        let start = Instant::now();
+        let (worker_parts, worker_input) = (unsolved.clone(), input.clone());
+        let jobs = isolated(format!("day {day}"), timeout, Duration::ZERO, move || {
+            aoc2023_rust::solve_parts(year, day, &worker_parts, &worker_input)
+        });
+        // time of getting the parts ready counts against the timeout of every part,
+        // and in the elapsed time of the first of them
+        let prepared_in = start.elapsed();
+        let mut preparing = prepared_in;
+        let solved: Vec<(Part, Result<Timings, Failure>, Duration)> = match jobs {
+            Ok(jobs) => jobs
+                .into_par_iter()
+                .map(|(part, job)| {
+                    let start = Instant::now();
+                    let name = format!("day {day} part {}", part.number());
+                    let solved = isolated(name, timeout, prepared_in, job)
+                        .and_then(|t| t.map_err(Failure::Solve));
+                    (part, solved, start.elapsed())
+                })
+                .collect(),
+            Err(failure) => unsolved
+                .iter()
+                .map(|&part| (part, Err(failure.clone()), Duration::ZERO))
+                .collect(),
+        };
+        for (part, solved, elapsed) in solved {
+            let elapsed = elapsed + std::mem::take(&mut preparing);
+            let timings = match solved {
+                Ok(timings) => timings,
+                Err(failure) => {
+                    results.push(result(part, Err(failure), elapsed, None, None));
+                    continue;
+                }
+            };
+            if use_cache {
+                if let Err(e) = cache::store(&key(part), &timings.answer) {
+                    eprintln!("warning: {e}");
+                }
+            }
+            let (parse, solve) = (timings.parse.first(), timings.solve.first());
+            results.push(result(
+                part,
+                Ok(timings.answer),
+                elapsed,
+                parse.copied(),
+                solve.copied(),
+            ));
+        }
+    }
+    results.sort_by_key(|r| r.part.number());
+    results
+}
+
+/// Reason why a part run by the launcher has no answer
+#[derive(Clone)]
+enum Failure {
+    /// Input file is missing or could not be read, with the reason
+    Input(String),
+    /// No solution or malformed input
+    Solve(Error),
+    /// The solution has not finished in the given time
+    Timeout(Duration),
+    /// The solution panicked, with the message of the panic
+    Panic(String),
+}
+
+/// Do the work in a worker thread, so that a solution which panics or takes longer than `timeout`
+/// fails only the parts depending on this work. `spent` is the part of the timeout used by earlier work
+/// of the same parts, e.g. parsing of their shared problem.
+/// A worker that ran out of time is left running until the launcher exits.
+fn isolated<T: Send + 'static>(
+    name: String,
+    timeout: Option<Duration>,
+    spent: Duration,
+    work: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, Failure> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::Builder::new()
+        .name(name)
+        .spawn(move || {
+            let done = panic::catch_unwind(AssertUnwindSafe(work));
+            // nobody waits for the result after the timeout
+            let _ = sender.send(done);
+        })
+        .unwrap_or_else(|e| panic!("Couldn't start worker thread: {e}"));
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout.saturating_sub(spent)),
+        None => receiver.recv().map_err(RecvTimeoutError::from),
+    };
+    match received {
+        Ok(Ok(done)) => Ok(done),
+        Ok(Err(payload)) => Err(Failure::Panic(panic_message(payload.as_ref()))),
+        Err(RecvTimeoutError::Timeout) => Err(Failure::Timeout(timeout.unwrap_or_default())),
+        Err(RecvTimeoutError::Disconnected) => Err(Failure::Panic(String::from(
+            "worker stopped without an answer",
+        ))),
+    }
+}
+
+/// Message given to `panic!`, which is a `&str` or a `String` unless the panic was raised with another value
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| String::from(*message))
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("unknown panic"))
+}
+
+/// Outcome of solving one part of one day
+struct RunResult {
+    day: Day,
+    part: Part,
+    answer: Result<Answer, Failure>,
+    elapsed: Duration,
+    /// Durations of parsing and solving, unknown for a cached answer. Parsing of the problem
+    /// shared by both parts is counted in the first one of them.
+    parse: Option<Duration>,
+    solve: Option<Duration>,
+    input_md5: String,
+}
+
+impl RunResult {
+    fn text(&self) -> String {
+        match &self.answer {
+            Ok(answer) => answer.to_string(),
+            Err(Failure::Input(_)) => String::from("<no input>"),
+            Err(Failure::Solve(Error::Parse(_))) => String::from("<parse error>"),
+            Err(Failure::Solve(_)) => String::from("<not solved>"),
+            Err(Failure::Timeout(_)) => String::from("TIMEOUT"),
+            Err(Failure::Panic(message)) => format!("PANIC({message})"),
+        }
+    }
+
+    fn record(&self, year: Year, mode: Mode, stdin: &Option<String>) -> report::Record {
+        let (status, error) = match &self.answer {
+            Ok(Answer::Unsolved)
+            | Err(Failure::Solve(Error::UnknownDay(..) | Error::UnsolvedPart(..))) => {
+                (report::Status::Unimplemented, None)
+            }
+            Ok(Answer::Failed(reason)) => (report::Status::Error, Some(reason.clone())),
+            Ok(_) => (report::Status::Solved, None),
+            Err(Failure::Input(reason)) => (report::Status::Error, Some(reason.clone())),
+            Err(Failure::Solve(e)) => (report::Status::Error, Some(e.to_string())),
+            Err(Failure::Timeout(timeout)) => (
+                report::Status::Timeout,
+                Some(format!("not solved in {timeout:?}")),
+            ),
+            Err(Failure::Panic(message)) => (report::Status::Panic, Some(message.clone())),
+        };
+        report::Record {
+            day: self.day,
+            part: self.part,
+            status,
+            answer: self
+                .answer
+                .as_ref()
+                .ok()
+                .filter(|answer| answer.is_solved())
+                .map(Answer::to_string),
+            parse: self.parse,
+            solve: self.solve,
+            input: match stdin {
+                Some(_) => String::from("<stdin>"),
+                None => aoc2023_rust::input_filepath(year, self.day, mode),
+            },
+            input_md5: self.input_md5.clone(),
+            error,
+        }
+    }
+}
+
+/// Print parse error of the input the day was solved with
+fn report_input_error(year: Year, day: Day, mode: Mode, stdin: &Option<String>, error: &Error) {
+    match stdin {
+        Some(data) => report_parse_error(day, "<stdin>", Some(data), error),
+        None => {
+            let path = aoc2023_rust::input_filepath(year, day, mode);
+            let input = encryption::read_input(Path::new(&path))
+                .ok()
+                .map(|input| String::from_utf8_lossy(&input).into_owned());
+            report_parse_error(day, &path, input.as_deref(), error)
+        }
+    }
+}
+
+/// Print parse error with the line of input where it was found
+fn report_parse_error(day: Day, source: &str, input: Option<&str>, error: &Error) {
+    let error = match error {
+        Error::Parse(e) => e,
+        error => return eprintln!("error: could not solve day {day}: {error}"),
+    };
+    let line = input
+        .and_then(|input| input.lines().nth(error.line - 1))
+        .unwrap_or("");
+    let number = error.line.to_string();
+    let pad = " ".repeat(number.len());
+    let marker = " ".repeat(error.column - 1) + &"^".repeat(error.text.chars().count().max(1));
+    eprintln!(
+        "error: could not parse input of day {day}: {}",
+        error.message
+    );
+    eprintln!("{pad}--> {source}:{}:{}", error.line, error.column);
+    eprintln!("{pad} |");
+    eprintln!("{number} | {line}");
+    eprintln!("{pad} | {marker}");
+}
+
+fn print_summary(results: &[RunResult], wall_clock: Duration) {
+    let width = results
+        .iter()
+        .map(|r| r.text().len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+    println!(
+        "{:>3} | {:>4} | {:<width$} | {:>12}",
+        "Day", "Part", "Answer", "Elapsed"
+    );
+    println!("{:-<3}-+-{:-<4}-+-{:-<width$}-+-{:-<12}", "", "", "", "");
+    results.iter().for_each(|r| {
+        println!(
+            "{:>3} | {:>4} | {:<width$} | {:>12}",
+            r.day,
+            r.part.number(),
+            r.text(),
+            format!("{:.3?}", r.elapsed)
+        );
+    });
+    let total: Duration = results.iter().map(|r| r.elapsed).sum();
+    println!(
+        "Total elapsed: {:.3?}, wall clock: {:.3?}",
+        total, wall_clock
+    );
+    let stars = results
+        .iter()
+        .filter(|r| r.answer.as_ref().is_ok_and(Answer::is_solved))
+        .count();
+    println!("Answered: {stars} of {} parts", results.len());
+}
+
+/// Print verdict for every answer, return `false` if any of them is wrong
+fn print_check(results: &[RunResult], year: Year, mode: Mode) -> bool {
+    let verdicts: Vec<answers::Verdict> = results
+        .iter()
+        .map(|r| {
+            let answer = r.text();
+            let expected = answers::load_expected(year, r.day, r.part, mode);
+            let verdict = answers::verify(expected, &answer);
+            let message = verdict_message(&verdict, &answer);
+            println!("Day {:>2} part {}: {}", r.day, r.part.number(), message);
+            verdict
+        })
+        .collect();
+    print_verdicts_summary(&verdicts)
+}
+
+/// Solve every example input of selected days and print verdicts, return exit code of the process
+fn run_fixtures(year: Year, days: RangeInclusive<Day>, parts: &[Part]) -> i32 {
+    let mut verdicts: Vec<answers::Verdict> = vec![];
+    let mut parse_failed = false;
+    for day in days {
+        let fixtures = fixtures::list(year, day);
+        if fixtures.is_empty() {
+            println!("Day {:>2}: no example inputs found", day);
+        }
+        for fixture in fixtures {
+            let input = match std::fs::read_to_string(&fixture.input_path) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Day {day}: could not read '{}': {e}", fixture.input_path);
+                    parse_failed = true;
+                    continue;
+                }
+            };
+            let mut reported: Option<Error> = None;
+            let applies = |part: Part| fixture.applies_to(part) && is_solved(year, day, part);
+            for &part in parts.iter().filter(|&&part| applies(part)) {
+                match get_solution(year, day, part, &input, false) {
+                    Ok(answer) => {
+                        let answer = answer.to_string();
+                        let verdict = answers::verify(fixture.expected(part), &answer);
+                        println!(
+                            "Day {:>2} part {} [{}]: {}",
+                            day,
+                            part.number(),
+                            fixture.name,
+                            verdict_message(&verdict, &answer)
+                        );
+                        verdicts.push(verdict);
+                    }
+                    Err(e) => {
+                        if reported.as_ref() != Some(&e) {
+                            report_parse_error(day, &fixture.input_path, Some(&input), &e);
+                            reported = Some(e);
+                        }
+                        parse_failed = true;
+                    }
+                }
+            }
+        }
+    }
+    let passed = print_verdicts_summary(&verdicts);
+    if parse_failed {
+        EXIT_PARSE_ERROR
+    } else if !passed {
+        1
     } else {
-        let answer_1 = get_solution(day, Part::One, mode, &stdin);
-        let answer_2 = get_solution(day, Part::Two, mode, &stdin);
-        println!("The part 1 answer is: {}", answer_1);
-        println!("The part 2 answer is: {}", answer_2);
+        0
     }
 }
 
-fn get_solution(day: Day, part: Part, mode: Mode, stdin: &Option<String>) -> String {
+/// Solve the part and send its answer to the site, return exit code of the process
+fn submit_answer(year: Year, day: Day, part: Part, stdin: &Option<String>, use_cache: bool) -> i32 {
+    let input = match load_input(year, day, Mode::Real, stdin) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Day {day}: {e}");
+            return EXIT_PARSE_ERROR;
+        }
+    };
+    let answer = match get_solution(year, day, part, &input, use_cache) {
+        Ok(answer) if !answer.is_solved() => {
+            eprintln!(
+                "Day {day} part {}: there is no answer to submit: {answer}",
+                part.number()
+            );
+            return 1;
+        }
+        Ok(answer) => answer.to_string(),
+        Err(e) => {
+            report_input_error(year, day, Mode::Real, stdin, &e);
+            return EXIT_PARSE_ERROR;
+        }
+    };
+    let outcome = match submit::previous_outcome(Path::new("."), year, day, part, &answer) {
+        Some(outcome) => {
+            println!("Answer '{answer}' has been submitted before");
+            outcome
+        }
+        None => match submit::submit(year, day, part, &answer) {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                eprintln!("Day {day}: {e}");
+                return 1;
+            }
+        },
+    };
+    println!(
+        "Day {day} part {}: answer '{answer}' is {outcome}",
+        part.number()
+    );
+    if outcome != submit::Outcome::Correct {
+        return 1;
+    }
+    if let Err(e) = answers::store(year, day, part, &answer) {
+        eprintln!("Day {day}: {e}");
+    }
+    0
+}
+
+fn verdict_message(verdict: &answers::Verdict, answer: &str) -> String {
+    match verdict {
+        answers::Verdict::Pass => format!("PASS    {}", answer),
+        answers::Verdict::Fail { expected } => {
+            format!("FAIL    expected '{}', got '{}'", expected, answer)
+        }
+        answers::Verdict::Missing => format!("MISSING got '{}'", answer),
+    }
+}
+
+/// Print counts of verdicts, return `false` if any answer is wrong
+fn print_verdicts_summary(verdicts: &[answers::Verdict]) -> bool {
+    let count = |f: fn(&answers::Verdict) -> bool| verdicts.iter().filter(|v| f(v)).count();
+    let failed = count(|v| matches!(v, answers::Verdict::Fail { .. }));
+    println!(
+        "Checked {} answers: {} passed, {} failed, {} missing",
+        verdicts.len(),
+        count(|v| matches!(v, answers::Verdict::Pass)),
+        failed,
+        count(|v| matches!(v, answers::Verdict::Missing)),
+    );
+    failed == 0
+}
+
+/// Print table of solutions of the year found in the registry
+fn print_list(year: Year) {
+    let registry = aoc2023_rust::solution::registry(year).unwrap_or_default();
+    let width = registry.iter().map(|e| e.title.len()).max().unwrap_or(0);
+    println!("{:>3} | {:<width$} | Parts", "Day", "Title");
+    println!("{:-<3}-+-{:-<width$}-+-{:-<5}", "", "", "");
+    registry.iter().for_each(|e| {
+        let parts: Vec<String> = e.parts.iter().map(|&p| p.number().to_string()).collect();
+        println!("{:>3} | {:<width$} | {}", e.day, e.title, parts.join(", "));
+    });
+}
+
+fn is_solved(year: Year, day: Day, part: Part) -> bool {
+    aoc2023_rust::solution::find(year, day).is_some_and(|e| e.implements(part))
+}
+
+/// Parse range of days in one of the forms `a..=b`, `a..b`, `a-b` or `a`
+fn parse_days(text: &str) -> Result<RangeInclusive<Day>, String> {
+    let parse_day = |t: &str| {
+        t.trim()
+            .parse::<Day>()
+            .map_err(|_| format!("'{t}' is not a valid day number"))
+    };
+    let (first, last) = if let Some((a, b)) = text.split_once("..=") {
+        (parse_day(a)?, parse_day(b)?)
+    } else if let Some((a, b)) = text.split_once("..") {
+        let b = parse_day(b)?;
+        (
+            parse_day(a)?,
+            b.checked_sub(1).ok_or("range of days is empty")?,
+        )
+    } else if let Some((a, b)) = text.split_once('-') {
+        (parse_day(a)?, parse_day(b)?)
+    } else {
+        let day = parse_day(text)?;
+        (day, day)
+    };
+    if !ALL_DAYS.contains(&first) || !ALL_DAYS.contains(&last) {
+        Err(format!(
+            "days must be numbers between 1 and 25, got '{text}'"
+        ))
+    } else if first > last {
+        Err(format!("range of days '{text}' is empty"))
+    } else {
+        Ok(first..=last)
+    }
+}
+
+/// Encrypt or decrypt all inputs, return exit code
+fn crypt_inputs(encrypt: bool) -> i32 {
+    let key = match encryption::InputKey::load() {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return 1;
+        }
+    };
+    let mut failed = false;
+    for path in encryption::input_files(Path::new("./input"), !encrypt) {
+        let result = if encrypt {
+            encryption::encrypt_input(&key, &path)
+        } else {
+            encryption::decrypt_input(&key, &path)
+        };
+        match result {
+            Ok(Some(written)) => println!("{} -> {}", path.display(), written.display()),
+            Ok(None) => println!("{}: unchanged", path.display()),
+            Err(e) => {
+                eprintln!("error: {e}");
+                failed = true;
+            }
+        }
+    }
+    i32::from(failed)
+}
+
+fn show_leaderboard(year: Year, id: u64, day: Option<Day>, refresh: bool) -> i32 {
+    let leaderboard = match utils::leaderboard::load(year, id, refresh) {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("error: leaderboard {id}: {e}");
+            return 1;
+        }
+    };
     match day {
-        1 => solution::day_01::DaySolution::run(part, mode, stdin),
-        2 => solution::day_02::DaySolution::run(part, mode, stdin),
-        3 => solution::day_03::DaySolution::run(part, mode, stdin),
-        4 => solution::day_04::DaySolution::run(part, mode, stdin),
-        5 => solution::day_05::DaySolution::run(part, mode, stdin),
-        6 => solution::day_06::DaySolution::run(part, mode, stdin),
-        7 => solution::day_07::DaySolution::run(part, mode, stdin),
-        8 => solution::day_08::Day::run(part, mode, stdin),
-        9 => solution::day_09::DaySolution::run(part, mode, stdin),
-        10 => solution::day_10::DaySolution::run(part, mode, stdin),
-        11 => solution::day_11::DaySolution::run(part, mode, stdin),
-        12 => solution::day_12::DaySolution::run(part, mode, stdin),
-        13 => solution::day_13::DaySolution::run(part, mode, stdin),
-        14 => solution::day_14::DaySolution::run(part, mode, stdin),
-        15 => solution::day_15::DaySolution::run(part, mode, stdin),
-        16 => solution::day_16::DaySolution::run(part, mode, stdin),
-        17 => solution::day_17::DaySolution::run(part, mode, stdin),
-        18 => solution::day_18::DaySolution::run(part, mode, stdin),
-        19 => solution::day_19::DaySolution::run(part, mode, stdin),
-        20 => solution::day_20::DaySolution::run(part, mode, stdin),
-        21 => solution::day_21::DaySolution::run(part, mode, stdin),
-        22 => solution::day_22::DaySolution::run(part, mode, stdin),
-        23 => solution::day_23::DaySolution::run(part, mode, stdin),
-        24 => solution::day_24::DaySolution::run(part, mode, stdin),
-        25 => solution::day_25::DaySolution::run(part, mode, stdin),
-        _ => panic!("unrecognized day '{day}', it must be number between 1 and 25"),
+        Some(day) => {
+            let local = [Part::One, Part::Two]
+                .map(|part| submit::solved_at(Path::new("."), year, day, part));
+            println!("Leaderboard {id}, day {day} of {year}, time since unlock:");
+            print!(
+                "{}",
+                utils::leaderboard::render_day(&leaderboard, day, local)
+            );
+        }
+        None => {
+            println!("Leaderboard {id} of {year}:");
+            print!("{}", utils::leaderboard::render(&leaderboard));
+        }
+    }
+    0
+}
+
+/// Show countdown until the day is unlocked, then download its input, retrying while the site is not ready
+fn wait_and_download(year: Year, day: Day) -> Result<(), utils::SiteError> {
+    let unlock = utils::unlock_time(year, day);
+    while let Ok(left) = (unlock - chrono::Utc::now()).to_std() {
+        let seconds = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+        eprint!(
+            "\rDay {day} of {year} unlocks in {:02}:{:02}:{:02} ",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        );
+        std::thread::sleep(left.min(Duration::from_secs(1)));
+    }
+    eprintln!("\rDay {day} of {year} is unlocked, downloading input");
+    utils::retry(
+        6,
+        Duration::from_secs(2),
+        || utils::download_input(year, day),
+        |e, delay| eprintln!("Day {day}: {e}, retrying in {}s", delay.as_secs()),
+    )
+    .map(|_| ())
+}
+
+/// Print the state of input files of the days, return exit code: 1 when some input is damaged
+fn verify_inputs(year: Year, days: RangeInclusive<Day>) -> i32 {
+    let single = days.start() == days.end();
+    let mut damaged = false;
+    for day in days {
+        let path = aoc2023_rust::input_filepath(year, day, Mode::Real);
+        let status = manifest::verify(Path::new("./input"), Path::new(&path));
+        // inputs that were never downloaded are not worth mentioning in the list of all days
+        if status == manifest::Status::Missing && !single {
+            continue;
+        }
+        damaged |= status.is_damaged();
+        println!("Day {day}: {path}: {status}");
+    }
+    i32::from(damaged)
+}
+
+/// Warn about inputs that may give wrong answers before solving them
+fn warn_damaged_inputs(year: Year, tasks: &[(Day, Part)]) {
+    let mut days: Vec<Day> = tasks.iter().map(|&(day, _)| day).collect();
+    days.dedup();
+    for day in days {
+        let path = aoc2023_rust::input_filepath(year, day, Mode::Real);
+        match manifest::verify(Path::new("./input"), Path::new(&path)) {
+            manifest::Status::Missing => (),
+            status if status.is_damaged() => eprintln!("warning: input {path}: {status}"),
+            _ => (),
+        }
+    }
+}
+
+/// Puzzle input of the day, either given on standard input or read from the input file
+fn load_input(
+    year: Year,
+    day: Day,
+    mode: Mode,
+    stdin: &Option<String>,
+) -> Result<String, encryption::CryptError> {
+    match stdin {
+        Some(data) => Ok(data.clone()),
+        None => {
+            let path = aoc2023_rust::input_filepath(year, day, mode);
+            let input = encryption::read_input(Path::new(&path))?;
+            Ok(String::from_utf8_lossy(&input).into_owned())
+        }
+    }
+}
+
+fn get_solution(
+    year: Year,
+    day: Day,
+    part: Part,
+    input: &str,
+    use_cache: bool,
+) -> Result<Answer, aoc2023_rust::Error> {
+    let key = cache::Key {
+        year,
+        day,
+        part,
+        input,
+    };
+    if let Some(answer) = use_cache.then(|| cache::load(&key)).flatten() {
+        return Ok(answer);
+    }
+    let answer = aoc2023_rust::solve_year(year, day, part, input)?;
+    if use_cache {
+        if let Err(e) = cache::store(&key, &answer) {
+            eprintln!("warning: {e}");
+        }
+    }
+    Ok(answer)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn cli() {
+        use clap::CommandFactory;
+        super::Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parse_days() {
+        assert_eq!(super::parse_days("3..=12"), Ok(3..=12));
+        assert_eq!(super::parse_days("3..12"), Ok(3..=11));
+        assert_eq!(super::parse_days("3-12"), Ok(3..=12));
+        assert_eq!(super::parse_days("7"), Ok(7..=7));
+        assert!(super::parse_days("0..=3").is_err());
+        assert!(super::parse_days("20..=26").is_err());
+        assert!(super::parse_days("12..=3").is_err());
+        assert!(super::parse_days("3..3").is_err());
+        assert!(super::parse_days("x").is_err());
+    }
+
+    #[test]
+    fn part() {
+        use super::{Cli, Part};
+        use clap::Parser;
+        let parts = |args: &[&str]| Cli::try_parse_from(args).map(|cli| cli.part.parts());
+        assert_eq!(parts(&["aoc", "-d", "1"]).unwrap(), &[Part::One, Part::Two]);
+        assert_eq!(
+            parts(&["aoc", "-d", "1", "--part", "2"]).unwrap(),
+            &[Part::Two]
+        );
+        assert_eq!(parts(&["aoc", "-d", "1", "-p", "1"]).unwrap(), &[Part::One]);
+        assert_eq!(
+            parts(&["aoc", "-d", "1", "--part", "both"]).unwrap(),
+            &[Part::One, Part::Two]
+        );
+        assert!(parts(&["aoc", "-d", "1", "--part", "3"]).is_err());
+    }
+
+    #[test]
+    fn submit() {
+        use super::Cli;
+        use clap::Parser;
+        assert!(Cli::try_parse_from(["aoc", "-d", "1", "-p", "2", "--submit"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "--all", "-p", "2", "--submit"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "-d", "1", "-p", "2", "-t", "--submit"]).is_err());
+    }
+
+    #[test]
+    fn year() {
+        use super::Cli;
+        use clap::Parser;
+        let year = |args: &[&str]| Cli::try_parse_from(args).map(|cli| cli.year);
+        // downloads come before the solutions of a new year
+        assert_eq!(
+            year(&["aoc", "--year", "2024", "--get-puzzle", "-d", "1"]).unwrap(),
+            2024
+        );
+        assert_eq!(year(&["aoc", "-y", "2015", "-d", "1"]).unwrap(), 2015);
+        assert!(year(&["aoc", "-y", "2014", "-d", "1"]).is_err());
+    }
+
+    #[test]
+    fn leaderboard() {
+        use super::{Cli, Command};
+        use clap::Parser;
+        let year = |args: &[&str]| match Cli::try_parse_from(args).map(|cli| cli.command) {
+            Ok(Some(Command::Leaderboard { year, .. })) => Some(year),
+            _ => None,
+        };
+        assert_eq!(year(&["aoc", "leaderboard", "--id", "1"]), Some(2023));
+        // 2022 has no solutions, its leaderboard is shown anyway
+        assert_eq!(
+            year(&["aoc", "leaderboard", "--id", "1", "--year", "2022"]),
+            Some(2022)
+        );
+        assert_eq!(
+            year(&["aoc", "leaderboard", "--id", "1", "-y", "2014"]),
+            None
+        );
+    }
+
+    #[test]
+    fn exit_codes() {
+        use clap::Parser;
+        let usage = super::Cli::try_parse_from(["aoc", "--bogus"]).unwrap_err();
+        assert_ne!(usage.exit_code(), super::EXIT_PARSE_ERROR);
+        assert_ne!(usage.exit_code(), super::EXIT_SOLUTION_FAILED);
+    }
+
+    #[test]
+    fn panic_message() {
+        let payload = std::panic::catch_unwind(|| panic!("Undefined case! {}", 7)).unwrap_err();
+        assert_eq!(super::panic_message(payload.as_ref()), "Undefined case! 7");
+        let payload = std::panic::catch_unwind(|| panic!("fixed")).unwrap_err();
+        assert_eq!(super::panic_message(payload.as_ref()), "fixed");
+        let payload = std::panic::catch_unwind(|| std::panic::panic_any(7)).unwrap_err();
+        assert_eq!(super::panic_message(payload.as_ref()), "unknown panic");
     }
 }
@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use super::{Day, Mode, Part, Year};
+
+/// Output format of the answers of a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Answers for people: sentences for one day, a table for several days
+    Text,
+    Json,
+    Csv,
+}
+
+/// Outcome of solving the part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    /// There is no solution for the day or the part
+    Unimplemented,
+    /// Puzzle input could not be parsed or the solution has not found the answer
+    Error,
+    /// The solution has not finished in the time given by `--timeout`
+    Timeout,
+    Panic,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unimplemented => "unimplemented",
+            Status::Error => "error",
+            Status::Timeout => "timeout",
+            Status::Panic => "panic",
+        }
+    }
+}
+
+/// Result of one part of one day as it is reported to machines
+pub struct Record {
+    pub day: Day,
+    pub part: Part,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// Durations are unknown for answers taken from the cache
+    pub parse: Option<Duration>,
+    pub solve: Option<Duration>,
+    /// Path of the input file or `<stdin>`
+    pub input: String,
+    /// MD5 of the input
+    pub input_md5: String,
+    pub error: Option<String>,
+}
+
+pub fn to_json(records: &[Record], year: Year, mode: Mode) -> String {
+    let mode = match mode {
+        Mode::Test => "test",
+        Mode::Real => "real",
+    };
+    let nanos = |d: Option<Duration>| d.map(|d| d.as_nanos() as u64);
+    let results: Vec<Value> = records
+        .iter()
+        .map(|r| {
+            json!({
+                "day": r.day,
+                "part": r.part.number(),
+                "status": r.status.name(),
+                "answer": r.answer,
+                "parse_ns": nanos(r.parse),
+                "solve_ns": nanos(r.solve),
+                "input": r.input,
+                "input_md5": r.input_md5,
+                "error": r.error,
+            })
+        })
+        .collect();
+    let report = json!({"year": year, "mode": mode, "results": results});
+    serde_json::to_string_pretty(&report).expect("report is serializable")
+}
+
+/// Field of CSV, quoted when it has a separator, a quote or a line break
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        String::from(text)
+    }
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let nanos = |d: Option<Duration>| d.map_or(String::new(), |d| d.as_nanos().to_string());
+    let mut lines = vec![String::from(
+        "day,part,status,answer,parse_ns,solve_ns,input,input_md5,error",
+    )];
+    lines.extend(records.iter().map(|r| {
+        [
+            r.day.to_string(),
+            r.part.number().to_string(),
+            String::from(r.status.name()),
+            csv_field(r.answer.as_deref().unwrap_or("")),
+            nanos(r.parse),
+            nanos(r.solve),
+            csv_field(&r.input),
+            r.input_md5.clone(),
+            csv_field(r.error.as_deref().unwrap_or("")),
+        ]
+        .join(",")
+    }));
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                part: Part::Two,
+                status: Status::Solved,
+                answer: Some(String::from("281")),
+                parse: Some(Duration::from_micros(15)),
+                solve: Some(Duration::from_nanos(700)),
+                input: String::from("./input/day_01.txt"),
+                input_md5: String::from("0cc175b9c0f1b6a831c399e269772661"),
+                error: None,
+            },
+            Record {
+                day: 8,
+                part: Part::One,
+                status: Status::Error,
+                answer: None,
+                parse: None,
+                solve: None,
+                input: String::from("<stdin>"),
+                input_md5: String::from("d41d8cd98f00b204e9800998ecf8427e"),
+                error: Some(String::from("could not parse input: \"x\", expected node")),
+            },
+        ]
+    }
+
+    #[test]
+    fn to_json() {
+        let json: Value =
+            serde_json::from_str(&super::to_json(&records(), 2023, Mode::Real)).unwrap();
+        assert_eq!(json["year"], 2023);
+        assert_eq!(json["mode"], "real");
+        assert_eq!(
+            json["results"][0],
+            json!({
+                "day": 1, "part": 2, "status": "solved", "answer": "281", "parse_ns": 15000,
+                "solve_ns": 700, "input": "./input/day_01.txt",
+                "input_md5": "0cc175b9c0f1b6a831c399e269772661", "error": null
+            })
+        );
+        assert_eq!(json["results"][1]["status"], "error");
+        assert_eq!(json["results"][1]["answer"], Value::Null);
+        assert_eq!(json["results"][1]["parse_ns"], Value::Null);
+        assert_eq!(json["results"][1]["input"], "<stdin>");
+        assert_eq!(
+            json["results"][1]["error"],
+            "could not parse input: \"x\", expected node"
+        );
+    }
+
+    #[test]
+    fn to_csv() {
+        assert_eq!(
+            super::to_csv(&records()),
+            "day,part,status,answer,parse_ns,solve_ns,input,input_md5,error\n\
+            1,2,solved,281,15000,700,./input/day_01.txt,0cc175b9c0f1b6a831c399e269772661,\n\
+            8,1,error,,,,<stdin>,d41d8cd98f00b204e9800998ecf8427e,\"could not parse input: \"\"x\"\", expected node\"\n"
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{Day, Year};
+
+/*
+New day is generated from the template module `src/solution/day_xx.rs` as `src/solution/yYYYY/day_NN.rs`
+with a stub of the test module, and is added to the `registry!` of the year.
+Module of a year that has no solutions yet is created and added to `YEARS` in `src/solution/mod.rs`.
+Title of the day is taken from the saved puzzle description (`--get-puzzle`), when there is one,
+otherwise it is `Day NN`. The stub lists no solved parts, so `--list` doesn't show it as solved.
+Empty default example `input_test/YYYY/day_NN.txt` is created for the example input of the puzzle.
+Sources are changed in place, so the command is run from the root of the repository.
+*/
+const TEMPLATE: &str = include_str!("../solution/day_xx.rs");
+
+const TEST_STUB: &str = r#"
+#[cfg(test)]
+mod tests {
+    use super::DaySolution as DS;
+    use crate::solution::Solution;
+    use crate::{Answer, Part};
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "EXAMPLE and the expected answer are not filled in yet"]
+    fn run() {
+        assert_eq!(DS::run(Part::One, String::from(EXAMPLE)), Ok(Answer::Number(0)));
+    }
+}
+"#;
+
+const YEAR_MODULE: &str = "// day modules refer to the shared items of solutions through `super`
+use super::{ParseError, Solution};
+";
+
+/// Source of the day module made from the template
+fn day_source(day: Day, title: &str) -> String {
+    TEMPLATE
+        .replace(
+            "const DAY_NUMBER: u8 = 0;",
+            &format!("const DAY_NUMBER: u8 = {day};"),
+        )
+        .replace(
+            r#"const TITLE: &'static str = "";"#,
+            &format!("const TITLE: &'static str = {title:?};"),
+        )
+        + TEST_STUB
+}
+
+/// Title of the day from the first heading of the saved description, e.g. `## --- Day 1: Trebuchet?! ---`
+fn title_of(description: &str) -> Option<String> {
+    let heading = description.lines().next()?.trim_start_matches('#').trim();
+    let heading = heading.trim_start_matches("---").trim_end_matches("---");
+    let (_, title) = heading.split_once(':')?;
+    Some(String::from(title.trim()))
+}
+
+/// Items of the list in the source, formatted one after another in lines of at most 100 characters
+fn wrap(items: &[String]) -> String {
+    let mut lines: Vec<String> = vec![];
+    for item in items {
+        match lines.last_mut() {
+            Some(line) if line.len() + item.len() + 2 <= 100 => {
+                line.push(' ');
+                line.push_str(item);
+                line.push(',');
+            }
+            _ => lines.push(format!("    {item},")),
+        }
+    }
+    lines.join("\n")
+}
+
+/// Add the module to `registry!(...)` of the year module source, keeping the modules ordered
+fn register(source: &str, module: &str) -> Result<String, String> {
+    let start = source
+        .find("registry!(")
+        .map(|i| i + "registry!(".len())
+        .ok_or("registry! of the year is not found")?;
+    let end = start
+        + source[start..]
+            .find(");")
+            .ok_or("registry! is not closed")?;
+    let mut modules: Vec<String> = source[start..end]
+        .split([',', ' ', '\n'])
+        .filter(|m| !m.is_empty())
+        .map(String::from)
+        .collect();
+    if modules.iter().any(|m| m == module) {
+        return Err(format!("{module} is already registered"));
+    }
+    modules.push(String::from(module));
+    modules.sort();
+    // same layout as rustfmt gives: one line when it fits, otherwise rows of modules
+    let one_line = format!("registry!({});", modules.join(", "));
+    if one_line.len() <= 100 {
+        return Ok(format!(
+            "{}{}{}",
+            &source[..start - "registry!(".len()],
+            one_line,
+            &source[end + 2..]
+        ));
+    }
+    Ok(format!(
+        "{}\n{}\n{}",
+        &source[..start],
+        wrap(&modules),
+        &source[end..]
+    ))
+}
+
+/// Declare the year module in the source of `solution/mod.rs` and add its registry to `YEARS`
+fn add_year(source: &str, year: Year) -> Result<String, String> {
+    let module = format!("y{year}");
+    let declarations: Vec<(usize, Year)> = source
+        .match_indices("pub mod y")
+        .filter_map(|(i, _)| {
+            let year = source[i + "pub mod y".len()..].split(';').next()?;
+            Some((i, year.parse().ok()?))
+        })
+        .collect();
+    if declarations.iter().any(|&(_, y)| y == year) {
+        return Err(format!("module {module} is already declared"));
+    }
+    let (last, _) = *declarations.last().ok_or("year modules are not found")?;
+    let line_end = last
+        + source[last..]
+            .find('\n')
+            .ok_or("unexpected end of source")?
+        + 1;
+    let mut years: Vec<Year> = declarations.iter().map(|&(_, y)| y).collect();
+    years.push(year);
+    years.sort();
+
+    let start = source
+        .find("pub static YEARS")
+        .ok_or("YEARS are not found")?;
+    let end = start + source[start..].find("];").ok_or("YEARS are not closed")? + 2;
+    let registries: Vec<String> = years
+        .iter()
+        .map(|y| format!("({y}, y{y}::REGISTRY)"))
+        .collect();
+    let statement = "pub static YEARS: &[(super::Year, &[Entry])] = &[";
+    let one_line = format!("{statement}{}];", registries.join(", "));
+    let years_statement = if one_line.len() <= 100 {
+        one_line
+    } else {
+        format!("{statement}\n    {},\n];", registries.join(",\n    "))
+    };
+    Ok(format!(
+        "{}pub mod {module};\n{}{years_statement}{}",
+        &source[..line_end],
+        &source[line_end..start],
+        &source[end..]
+    ))
+}
+
+/// Generate the module of the day under `root`, return the created and changed files
+pub fn create(root: &Path, year: Year, day: Day) -> Result<Vec<PathBuf>, String> {
+    let solution = root.join("src/solution");
+    let year_folder = solution.join(format!("y{year}"));
+    let module = format!("day_{:0>2}", day);
+    let path = year_folder.join(format!("{module}.rs"));
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|e| format!("could not read '{}': {e}", path.display()))
+    };
+    let write = |path: &Path, text: &str| {
+        path.parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(path, text))
+            .map_err(|e| format!("could not write '{}': {e}", path.display()))
+    };
+    let mut changed = vec![];
+
+    // sources are checked before anything is written, so that a failure leaves them untouched
+    let year_module = year_folder.join("mod.rs");
+    let (year_source, solution_source) = if year_module.exists() {
+        (read(&year_module)?, None)
+    } else {
+        let solution_mod = solution.join("mod.rs");
+        let source = add_year(&read(&solution_mod)?, year)?;
+        (
+            format!("{YEAR_MODULE}\nregistry!();\n"),
+            Some((solution_mod, source)),
+        )
+    };
+    let year_source = register(&year_source, &module)?;
+
+    let description =
+        aoc2023_rust::year_folder(&root.join("puzzles"), year).join(format!("{module}.md"));
+    let title = fs::read_to_string(description)
+        .ok()
+        .and_then(|text| title_of(&text))
+        .unwrap_or_else(|| format!("Day {day:0>2}"));
+    write(&path, &day_source(day, &title))?;
+    changed.push(path);
+    write(&year_module, &year_source)?;
+    changed.push(year_module);
+    if let Some((path, source)) = solution_source {
+        write(&path, &source)?;
+        changed.push(path);
+    }
+    let example =
+        aoc2023_rust::year_folder(&root.join("input_test"), year).join(format!("{module}.txt"));
+    if !example.exists() {
+        write(&example, "")?;
+        changed.push(example);
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLUTION_MOD: &str = "mod day_xx;
+// solutions per year
+pub mod y2023;
+// modules of 2023 were here before other years were added, keep their paths working
+pub use y2023::*;
+
+/// Registries of solutions per year, ordered by year
+pub static YEARS: &[(super::Year, &[Entry])] = &[(2023, y2023::REGISTRY)];
+";
+
+    #[test]
+    fn day_source() {
+        let source = super::day_source(7, "Camel \"Cards\"");
+        assert!(source.contains("const DAY_NUMBER: u8 = 7;"));
+        assert!(source.contains(r#"const TITLE: &'static str = "Camel \"Cards\"";"#));
+        assert!(source.contains("const PARTS: &'static [Part] = &[];"));
+        assert!(source.contains("mod tests {"));
+        assert_eq!(
+            title_of("## --- Day 1: Trebuchet?! ---\n\nSomething is wrong"),
+            Some(String::from("Trebuchet?!"))
+        );
+    }
+
+    #[test]
+    fn register() {
+        let modules: Vec<String> = (1..=25)
+            .filter(|&d| d != 13)
+            .map(|d| format!("day_{d:0>2}"))
+            .collect();
+        let source = format!(
+            "use super::Solution;\n\nregistry!(\n{}\n);\n",
+            wrap(&modules)
+        );
+        let registered = super::register(&source, "day_13").unwrap();
+        assert_eq!(
+            registered,
+            "use super::Solution;\n\nregistry!(\n    \
+            day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11, day_12,\n    \
+            day_13, day_14, day_15, day_16, day_17, day_18, day_19, day_20, day_21, day_22, day_23, day_24,\n    \
+            day_25,\n);\n"
+        );
+        assert!(super::register(&registered, "day_13").is_err());
+        assert_eq!(
+            super::register("registry!();", "day_01"),
+            Ok(String::from("registry!(day_01);"))
+        );
+    }
+
+    #[test]
+    fn create() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let solution = root.join("src/solution");
+        fs::create_dir_all(solution.join("y2023")).unwrap();
+        fs::create_dir_all(root.join("puzzles/2024")).unwrap();
+        fs::write(solution.join("mod.rs"), SOLUTION_MOD).unwrap();
+        fs::write(
+            solution.join("y2023/mod.rs"),
+            "registry!(\n    day_01,\n);\n",
+        )
+        .unwrap();
+        fs::write(solution.join("y2023/day_01.rs"), "").unwrap();
+        fs::write(
+            root.join("puzzles/2024/day_05.md"),
+            "## --- Day 5: Print Queue ---\n",
+        )
+        .unwrap();
+
+        assert!(super::create(&root, 2023, 1).is_err());
+        assert_eq!(
+            super::create(&root, 2023, 2).unwrap(),
+            [
+                solution.join("y2023/day_02.rs"),
+                solution.join("y2023/mod.rs"),
+                root.join("input_test/day_02.txt")
+            ]
+        );
+        let day = fs::read_to_string(solution.join("y2023/day_02.rs")).unwrap();
+        assert!(day.contains(r#"const TITLE: &'static str = "Day 02";"#));
+        assert_eq!(
+            fs::read_to_string(solution.join("y2023/mod.rs")).unwrap(),
+            "registry!(day_01, day_02);\n"
+        );
+
+        super::create(&root, 2024, 5).unwrap();
+        let day = fs::read_to_string(solution.join("y2024/day_05.rs")).unwrap();
+        assert!(day.contains(r#"const TITLE: &'static str = "Print Queue";"#));
+        assert!(fs::read_to_string(solution.join("y2024/mod.rs"))
+            .unwrap()
+            .ends_with("registry!(day_05);\n"));
+        let solution_mod = fs::read_to_string(solution.join("mod.rs")).unwrap();
+        assert!(solution_mod.contains("pub mod y2023;\npub mod y2024;\n"));
+        assert!(solution_mod.contains("= &[(2023, y2023::REGISTRY), (2024, y2024::REGISTRY)];"));
+        assert!(root.join("input_test/2024/day_05.txt").is_file());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -1,19 +1,21 @@
+use super::ParseError;
+use crate::Part;
+
 type P = ();
 
 pub struct DaySolution(P);
 
 impl super::Solution for DaySolution {
-    const DAY_NUMBER: u8 = 4;
+    const DAY_NUMBER: u8 = 0;
+    const TITLE: &'static str = "";
+    // add the parts here as they get solved
+    const PARTS: &'static [Part] = &[];
 
     type Answer = Option<i32>;
     type Problem = P;
 
-    fn parse_input_part_1(_text_input: String) -> Self::Problem {
-        unimplemented!();
-    }
-
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
+    fn parse_input_part_1(_text_input: String) -> Result<Self::Problem, ParseError> {
+        Ok(())
     }
 
     fn solve_part_1(_problem: Self::Problem) -> Self::Answer {
@@ -23,11 +25,4 @@ impl super::Solution for DaySolution {
     fn solve_part_2(_problem: Self::Problem) -> Self::Answer {
         None
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => String::new(),
-        }
-    }
 }
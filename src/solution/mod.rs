@@ -1,80 +1,318 @@
-use std::fs;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
-pub trait Solution {
+/// Problem found in puzzle input, position is counted from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error about `text` found in `input`. When `text` is a slice of `input`
+    /// (which is the case for `lines`, `split` and regex captures) its exact position is reported,
+    /// otherwise the first occurence of `text` is used.
+    pub fn at(input: &str, text: &str, message: impl Into<String>) -> ParseError {
+        let start = input.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|o| o + text.len() <= input.len())
+            .or_else(|| input.find(text))
+            .unwrap_or(0);
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        ParseError {
+            line,
+            column: input[line_start..offset].chars().count() + 1,
+            text: String::from(text),
+            message: message.into(),
+        }
+    }
+
+    /// Move error found in a part of input down by given number of lines,
+    /// e.g. by index of the line when each line is parsed separately
+    pub fn offset_lines(self, lines: usize) -> ParseError {
+        ParseError {
+            line: self.line + lines,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}: '{}'",
+            self.message, self.line, self.column, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse `text` found in `input` into number
+pub fn parse_number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.trim()
+        .parse()
+        .map_err(|_| ParseError::at(input, text, format!("'{}' is not a valid number", text)))
+}
+
+/// Durations of parsing and solving measured over several repetitions.
+/// Parsing is missing for a part that solves the problem parsed for another part.
+pub struct Timings {
+    pub answer: super::Answer,
+    pub parse: Vec<Duration>,
+    pub solve: Vec<Duration>,
+}
+
+/// Part made ready by `Solution::jobs` to be solved apart from the other parts, e.g. in a thread of its own
+pub type Job = Box<dyn FnOnce() -> Result<Timings, ParseError> + Send>;
+
+/// Jobs of the parts, or the error of parsing the problem they share
+pub type Jobs = Result<Vec<(super::Part, Job)>, ParseError>;
+
+pub trait Solution: 'static {
     const DAY_NUMBER: u8;
+    const TITLE: &'static str;
+    /// Parts of the puzzle that are solved
+    const PARTS: &'static [super::Part] = &[super::Part::One, super::Part::Two];
+    /// Both parts solve the problem given by `parse_input_part_1`, so the input is parsed once.
+    /// Days that read the input of part 2 differently turn it off and override `parse_input_part_2`.
+    const SHARED_PROBLEM: bool = true;
 
-    type Answer;
-    type Problem;
+    /// Answer of the day, e.g. `Option<u32>` where `None` means that the part is not solved
+    type Answer: Into<super::Answer>;
+    /// Problem is cloned for all but the last part when the parts solve the same one
+    type Problem: Clone + Send + 'static;
 
     // Solution Trait
-    fn get_filepath(mode: super::Mode) -> String {
-        let day_no = Self::DAY_NUMBER;
+    fn run(part: super::Part, input: String) -> Result<super::Answer, ParseError> {
+        let problem = Self::parse_part(part, input)?;
+        Ok(Self::solve_part(part, problem).into())
+    }
 
-        let folder = match mode {
-            super::Mode::Test => "input_test",
-            super::Mode::Real => "input",
+    /// Jobs solving the parts independently of each other. When the parts solve the same problem,
+    /// the input is parsed here once: the last job takes the problem, the others get its clones,
+    /// and the parsing time is reported by the first job only. Otherwise every job parses its own input.
+    fn jobs(parts: &[super::Part], input: String) -> Jobs {
+        let Some((&last, others)) = parts.split_last() else {
+            return Ok(vec![]);
+        };
+        if !Self::SHARED_PROBLEM {
+            let job = |part| -> (super::Part, Job) {
+                let input = input.clone();
+                let job = move || {
+                    let start = Instant::now();
+                    let problem = Self::parse_part(part, input)?;
+                    Ok(solve_timed::<Self>(part, problem, vec![start.elapsed()]))
+                };
+                (part, Box::new(job))
+            };
+            return Ok(parts.iter().map(|&part| job(part)).collect());
+        }
+        let start = Instant::now();
+        let problem = Self::parse_input_part_1(input)?;
+        let mut parse = vec![start.elapsed()];
+        let mut job = |part, problem| -> (super::Part, Job) {
+            let parse = std::mem::take(&mut parse);
+            (
+                part,
+                Box::new(move || Ok(solve_timed::<Self>(part, problem, parse))),
+            )
         };
-        // use formatting to construct the name of the file
-        format!("./{f}/day_{n:0>2}.txt", f = folder, n = day_no)
+        let mut jobs: Vec<_> = others
+            .iter()
+            .map(|&part| job(part, problem.clone()))
+            .collect();
+        jobs.push(job(last, problem));
+        Ok(jobs)
     }
 
-    fn load_file_input(mode: super::Mode) -> String {
-        let path = Self::get_filepath(mode);
-        fs::read_to_string(path).expect("Couldn't read file")
+    fn bench(part: super::Part, input: String, repetitions: usize) -> Result<Timings, ParseError> {
+        let mut timings = Timings {
+            answer: super::Answer::Unsolved,
+            parse: Vec::with_capacity(repetitions),
+            solve: Vec::with_capacity(repetitions),
+        };
+        for _ in 0..repetitions {
+            let input = input.clone();
+            let start = Instant::now();
+            let problem = Self::parse_part(part, input)?;
+            timings.parse.push(start.elapsed());
+            let start = Instant::now();
+            let answer = Self::solve_part(part, problem);
+            timings.solve.push(start.elapsed());
+            timings.answer = answer.into();
+        }
+        Ok(timings)
     }
 
-    fn run(part: super::Part, mode: super::Mode, stdin: &Option<String>) -> String {
-        let input = match stdin {
-            Some(data) => data.clone(),
-            _ => Self::load_file_input(mode),
-        };
-        let problem = match part {
-            super::Part::One => Self::parse_input_part_1(input),
-            super::Part::Two => Self::parse_input_part_2(input),
-        };
-        let answer = match part {
+    fn parse_part(part: super::Part, text_input: String) -> Result<Self::Problem, ParseError> {
+        match part {
+            super::Part::One => Self::parse_input_part_1(text_input),
+            super::Part::Two if Self::SHARED_PROBLEM => Self::parse_input_part_1(text_input),
+            super::Part::Two => Self::parse_input_part_2(text_input),
+        }
+    }
+
+    fn solve_part(part: super::Part, problem: Self::Problem) -> Self::Answer {
+        match part {
             super::Part::One => Self::solve_part_1(problem),
             super::Part::Two => Self::solve_part_2(problem),
-        };
-        Self::show_answer(answer)
+        }
     }
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem;
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError>;
 
-    fn parse_input_part_2(text_input: String) -> Self::Problem;
+    fn parse_input_part_2(text_input: String) -> Result<Self::Problem, ParseError> {
+        Self::parse_input_part_1(text_input)
+    }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer;
 
     fn solve_part_2(problem: Self::Problem) -> Self::Answer;
+}
+
+/// Answer of the part with the durations of parsing its problem and of solving it
+fn solve_timed<S: Solution + ?Sized>(
+    part: super::Part,
+    problem: S::Problem,
+    parse: Vec<Duration>,
+) -> Timings {
+    let start = Instant::now();
+    let answer = S::solve_part(part, problem);
+    Timings {
+        answer: answer.into(),
+        parse,
+        solve: vec![start.elapsed()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn parse_error_at() {
+        let input = "abc\ndef ghi\njkl";
+        let ghi = input.lines().nth(1).unwrap().split(' ').nth(1).unwrap();
+        assert_eq!(
+            ParseError::at(input, ghi, "bad"),
+            ParseError {
+                line: 2,
+                column: 5,
+                text: String::from("ghi"),
+                message: String::from("bad"),
+            }
+        );
+        let error = ParseError::at(input, "jkl", "bad");
+        assert_eq!((error.line, error.column), (3, 1));
+        let error = ParseError::at("x1", "1", "bad").offset_lines(4);
+        assert_eq!((error.line, error.column), (5, 2));
+    }
+    #[test]
+    fn registry() {
+        let entries = super::registry(2023).unwrap();
+        assert_eq!(entries.len(), 25);
+        assert!(entries.windows(2).all(|w| w[0].day < w[1].day));
+        assert_eq!(find(2023, 8).map(|e| e.title), Some("Haunted Wasteland"));
+        assert!(find(2023, 25).is_some_and(|e| !e.implements(crate::Part::Two)));
+        assert!(find(2023, 26).is_none());
+        assert!(find(2015, 1).is_none());
+        assert!(YEARS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+    #[test]
+    fn parse_number() {
+        assert_eq!(super::parse_number::<u32>("a 12", "12"), Ok(12));
+        assert_eq!(
+            super::parse_number::<u8>("a 300", "300").map_err(|e| e.column),
+            Err(3)
+        );
+    }
+}
+
+/// Solution of one day as it is listed in the registry
+pub struct Entry {
+    pub day: u8,
+    pub title: &'static str,
+    pub parts: &'static [super::Part],
+    pub run: fn(super::Part, String) -> Result<super::Answer, ParseError>,
+    pub bench: fn(super::Part, String, usize) -> Result<Timings, ParseError>,
+    pub jobs: fn(&[super::Part], String) -> Jobs,
+}
+
+impl Entry {
+    pub const fn of<S: Solution>() -> Entry {
+        Entry {
+            day: S::DAY_NUMBER,
+            title: S::TITLE,
+            parts: S::PARTS,
+            run: S::run,
+            bench: S::bench,
+            jobs: S::jobs,
+        }
+    }
 
-    fn show_answer(answer: Self::Answer) -> String;
+    pub fn implements(&self, part: super::Part) -> bool {
+        self.parts.contains(&part)
+    }
+}
+
+/// Stop compilation when the day number of a solution is out of the calendar or taken twice
+pub(crate) const fn validate(entries: &[Entry]) {
+    let mut i = 0;
+    while i < entries.len() {
+        let day = entries[i].day;
+        assert!(day >= 1 && day <= 25, "day number must be between 1 and 25");
+        let mut j = i + 1;
+        while j < entries.len() {
+            assert!(
+                day != entries[j].day,
+                "two solutions have the same day number"
+            );
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+/// Declare modules of solutions of a year and list them in the registry of the year
+macro_rules! registry {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        const ENTRIES: &[$crate::solution::Entry] =
+            &[$($crate::solution::Entry::of::<$module::DaySolution>()),*];
+        const _: () = $crate::solution::validate(ENTRIES);
+
+        /// Every solution of the year, ordered by day number
+        pub static REGISTRY: &[$crate::solution::Entry] = ENTRIES;
+    };
 }
 
 // template module
 mod day_xx;
-// real modules
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_06;
-pub mod day_07;
-pub mod day_08;
-pub mod day_09;
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
-pub mod day_13;
-pub mod day_14;
-pub mod day_15;
-pub mod day_16;
-pub mod day_17;
-pub mod day_18;
-pub mod day_19;
-pub mod day_20;
-pub mod day_21;
-pub mod day_22;
-pub mod day_23;
-pub mod day_24;
-pub mod day_25;
+// solutions per year
+pub mod y2023;
+// modules of 2023 were here before other years were added, keep their paths working
+pub use y2023::*;
+
+/// Registries of solutions per year, ordered by year
+pub static YEARS: &[(super::Year, &[Entry])] = &[(2023, y2023::REGISTRY)];
+
+/// Registry of solutions of the year
+pub fn registry(year: super::Year) -> Option<&'static [Entry]> {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map(|(_, entries)| *entries)
+}
+
+/// Solution registered for the day of the year
+pub fn find(year: super::Year, day: u8) -> Option<&'static Entry> {
+    registry(year)?.iter().find(|entry| entry.day == day)
+}
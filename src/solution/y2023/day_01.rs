@@ -1,3 +1,4 @@
+use super::ParseError;
 use regex::Regex;
 
 type P = Vec<u32>;
@@ -5,22 +6,22 @@ type P = Vec<u32>;
 pub struct DaySolution(P);
 
 impl DaySolution {
-    fn parse_one_line_1(line: &str) -> u32 {
+    fn parse_one_line_1(line: &str) -> Result<u32, ParseError> {
         let re = Regex::new(r#"\d{1}"#).unwrap();
         let d1 = re
             .captures_iter(line)
             .nth(0)
             .map(|cap| cap.get(0).unwrap().as_str().parse::<u32>().unwrap())
-            .unwrap();
+            .ok_or_else(|| ParseError::at(line, line, "no digit found in line"))?;
         let d0 = re
             .captures_iter(line)
             .last()
             .map(|cap| cap.get(0).unwrap().as_str().parse::<u32>().unwrap())
-            .unwrap();
-        d1 * 10 + d0
+            .ok_or_else(|| ParseError::at(line, line, "no digit found in line"))?;
+        Ok(d1 * 10 + d0)
     }
 
-    fn parse_one_line_2(line: &str) -> u32 {
+    fn parse_one_line_2(line: &str) -> Result<u32, ParseError> {
         fn extract_digit(x: &str) -> u32 {
             match x {
                 "0" | "zero" => 0,
@@ -46,14 +47,14 @@ impl DaySolution {
             .nth(0)
             .map(|cap| cap.get(0).unwrap().as_str())
             .map(extract_digit)
-            .unwrap();
+            .ok_or_else(|| ParseError::at(line, line, "no digit found in line"))?;
         let d0 = re_r
             .captures_iter(line)
             .last()
             .map(|cap| cap.get(1).unwrap().as_str())
             .map(extract_digit)
-            .unwrap();
-        d1 * 10 + d0
+            .ok_or_else(|| ParseError::at(line, line, "no digit found in line"))?;
+        Ok(d1 * 10 + d0)
     }
 }
 
@@ -62,18 +63,22 @@ impl super::Solution for DaySolution {
     type Problem = P;
 
     const DAY_NUMBER: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+    const SHARED_PROBLEM: bool = false;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line_1)
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line_1(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
+    fn parse_input_part_2(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line_2)
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line_2(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
@@ -86,13 +91,6 @@ impl super::Solution for DaySolution {
         let answer = problem.iter().sum();
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => String::new(),
-        }
-    }
 }
 
 #[cfg(test)]
@@ -102,22 +100,35 @@ mod tests {
 
     #[test]
     fn parse_one_line_1() {
-        assert_eq!(super::DaySolution::parse_one_line_1("1abc2"), 12)
+        assert_eq!(super::DaySolution::parse_one_line_1("1abc2"), Ok(12));
+        assert_eq!(
+            super::DaySolution::parse_one_line_1("abc").map_err(|e| e.column),
+            Err(1)
+        );
     }
 
     #[test]
     fn parse_one_line_2() {
-        assert_eq!(super::DaySolution::parse_one_line_2("two1nine"), 29);
-        assert_eq!(super::DaySolution::parse_one_line_2("eightwothree"), 83);
-        assert_eq!(super::DaySolution::parse_one_line_2("abcone2threexyz"), 13);
-        assert_eq!(super::DaySolution::parse_one_line_2("xtwone3four"), 24);
-        assert_eq!(super::DaySolution::parse_one_line_2("4nineeightseven2"), 42);
-        assert_eq!(super::DaySolution::parse_one_line_2("zoneight234"), 14);
-        assert_eq!(super::DaySolution::parse_one_line_2("7pqrstsixteen"), 76);
-        assert_eq!(super::DaySolution::parse_one_line_2("eightwo"), 82);
+        assert_eq!(super::DaySolution::parse_one_line_2("two1nine"), Ok(29));
+        assert_eq!(super::DaySolution::parse_one_line_2("eightwothree"), Ok(83));
+        assert_eq!(
+            super::DaySolution::parse_one_line_2("abcone2threexyz"),
+            Ok(13)
+        );
+        assert_eq!(super::DaySolution::parse_one_line_2("xtwone3four"), Ok(24));
+        assert_eq!(
+            super::DaySolution::parse_one_line_2("4nineeightseven2"),
+            Ok(42)
+        );
+        assert_eq!(super::DaySolution::parse_one_line_2("zoneight234"), Ok(14));
+        assert_eq!(
+            super::DaySolution::parse_one_line_2("7pqrstsixteen"),
+            Ok(76)
+        );
+        assert_eq!(super::DaySolution::parse_one_line_2("eightwo"), Ok(82));
         assert_eq!(
             super::DaySolution::parse_one_line_2("twocsfzd1eight7eightwovm"),
-            22
+            Ok(22)
         );
     }
 }
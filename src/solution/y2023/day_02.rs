@@ -1,3 +1,4 @@
+use super::{parse_number, ParseError};
 use regex::Regex;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -6,7 +7,7 @@ pub struct BallSet {
     green: u8,
     blue: u8,
 }
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Game {
     id: u32,
     sets: Vec<BallSet>,
@@ -39,52 +40,53 @@ impl DaySolution {
         }
     }
 
-    fn parse_one_set(set_of_balls: &str) -> BallSet {
+    fn parse_one_set(line: &str, set_of_balls: &str) -> Result<BallSet, ParseError> {
         let re_balls = Regex::new(r#"(\d+) (\w+)"#).unwrap();
-        re_balls
+        let set = re_balls
             .captures_iter(set_of_balls)
             .map(|c| {
-                let n: u8 = c.get(1).unwrap().as_str().parse().unwrap();
+                let n: u8 = parse_number(line, c.get(1).unwrap().as_str())?;
                 let c: &str = c.get(2).unwrap().as_str();
                 match c {
-                    "red" => BallSet {
+                    "red" => Ok(BallSet {
                         red: n,
                         ..Self::ZERO_SET
-                    },
-                    "green" => BallSet {
+                    }),
+                    "green" => Ok(BallSet {
                         green: n,
                         ..Self::ZERO_SET
-                    },
-                    "blue" => BallSet {
+                    }),
+                    "blue" => Ok(BallSet {
                         blue: n,
                         ..Self::ZERO_SET
-                    },
-                    _ => panic!("unrecognized color: '{}'", c),
+                    }),
+                    _ => Err(ParseError::at(line, c, "unrecognized color")),
                 }
             })
-            .fold(Self::ZERO_SET, Self::sum_ball_sets)
+            .try_fold(Self::ZERO_SET, |z, x| Ok(Self::sum_ball_sets(z, x?)));
+        set
     }
 
-    fn parse_one_line(line: &str) -> Game {
+    fn parse_one_line(line: &str) -> Result<Game, ParseError> {
         let re_game = Regex::new(r#"^Game (\d+):(.*)$"#).unwrap();
-        let (game_id, game_sets): (u32, &str) = re_game
+        let (game_id, game_sets): (&str, &str) = re_game
             .captures(line)
             .map(|c| {
-                let id: u32 = c.get(1).unwrap().as_str().parse().unwrap();
+                let id = c.get(1).unwrap().as_str();
                 let gs = c.get(2).unwrap().as_str();
                 (id, gs)
             })
-            .unwrap();
+            .ok_or_else(|| ParseError::at(line, line, "expected 'Game <id>: <sets>'"))?;
         let re_set = Regex::new(r#"[\d a-z,]+"#).unwrap();
         let game_sets = re_set
             .captures_iter(game_sets)
             .map(|c| c.get(0).unwrap().as_str())
-            .map(Self::parse_one_set)
-            .collect();
-        Game {
-            id: game_id,
+            .map(|set| Self::parse_one_set(line, set))
+            .collect::<Result<_, _>>()?;
+        Ok(Game {
+            id: parse_number(line, game_id)?,
             sets: game_sets,
-        }
+        })
     }
 }
 
@@ -93,18 +95,16 @@ impl super::Solution for DaySolution {
     type Problem = P;
 
     const DAY_NUMBER: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line)
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(text_input)
-    }
-
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
         let (max_r, max_g, max_b) = (12_u8, 13_u8, 14_u8);
         let valid_id_sum: u32 = problem
@@ -137,13 +137,6 @@ impl super::Solution for DaySolution {
             .sum();
         Some(power_sum)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
 
 #[cfg(test)]
@@ -161,7 +154,7 @@ mod tests {
         //BallSet { red: 0, green: 2, blue: 0 }
         assert_eq!(
             DaySolution::parse_one_line(line),
-            Game {
+            Ok(Game {
                 id: 1,
                 sets: vec![
                     BallSet {
@@ -180,8 +173,13 @@ mod tests {
                         blue: 0
                     },
                 ]
-            }
-        )
+            })
+        );
+        let line = "Game 2: 3 blue, 4 purple";
+        assert_eq!(
+            DaySolution::parse_one_line(line).map_err(|e| (e.column, e.text)),
+            Err((19, String::from("purple")))
+        );
     }
 }
 
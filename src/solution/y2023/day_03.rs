@@ -2,6 +2,8 @@ use std::collections::HashSet;
 
 use regex::Regex;
 
+use super::ParseError;
+
 type Row = usize;
 type Col = usize;
 
@@ -133,16 +135,13 @@ impl super::Solution for DaySolution {
     type Problem = P;
 
     const DAY_NUMBER: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
-        text_input
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
+        Ok(text_input
             .lines()
             .map(|l| l.chars().collect::<Vec<char>>())
-            .collect()
-    }
-
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
+            .collect())
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
@@ -232,11 +231,4 @@ impl super::Solution for DaySolution {
             .sum();
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
@@ -1,3 +1,4 @@
+use super::{parse_number, ParseError};
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 
@@ -15,42 +16,41 @@ pub type P = Vec<Card>;
 pub struct DaySolution(P);
 
 impl DaySolution {
-    fn line_of_num_to_set(line_of_num: &str) -> NumSet {
+    fn line_of_num_to_set(line: &str, line_of_num: &str) -> Result<NumSet, ParseError> {
         Regex::new(r#"\d+"#)
             .unwrap()
             .captures_iter(line_of_num)
-            .map(|c| c.get(0).unwrap().as_str().parse::<u32>().unwrap())
+            .map(|c| parse_number(line, c.get(0).unwrap().as_str()))
             .collect()
     }
-    fn parse_one_line(line: &str) -> Card {
+    fn parse_one_line(line: &str) -> Result<Card, ParseError> {
         let re = Regex::new(r#"Card +(\d+): ([ \d]+)\|([ \d]+)"#).unwrap();
-        re.captures(line)
-            .map(|c| Card {
-                no: c.get(1).unwrap().as_str().parse::<u32>().unwrap(),
-                numbers_win: Self::line_of_num_to_set(c.get(2).unwrap().as_str()),
-                numbers_have: Self::line_of_num_to_set(c.get(3).unwrap().as_str()),
-            })
-            .expect(format!("Could not unwrap values out of input '{}'", line).as_str())
+        let c = re.captures(line).ok_or_else(|| {
+            ParseError::at(line, line, "expected 'Card <no>: <numbers> | <numbers>'")
+        })?;
+        Ok(Card {
+            no: parse_number(line, c.get(1).unwrap().as_str())?,
+            numbers_win: Self::line_of_num_to_set(line, c.get(2).unwrap().as_str())?,
+            numbers_have: Self::line_of_num_to_set(line, c.get(3).unwrap().as_str())?,
+        })
     }
 }
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
 
     type Answer = Option<u32>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line)
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
-    }
-
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
         let answer = problem
             .iter()
@@ -75,13 +75,6 @@ impl super::Solution for DaySolution {
 
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
 
 #[cfg(test)]
@@ -96,12 +89,12 @@ mod tests {
     #[test]
     fn line_of_num_to_set() {
         assert_eq!(
-            DaySolution::line_of_num_to_set("  1 12"),
-            HashSet::from([1_u32, 12])
+            DaySolution::line_of_num_to_set("  1 12", "  1 12"),
+            Ok(HashSet::from([1_u32, 12]))
         );
         assert_eq!(
-            DaySolution::line_of_num_to_set("10  9 22 01"),
-            HashSet::from([1_u32, 9, 10, 22])
+            DaySolution::line_of_num_to_set("10  9 22 01", "10  9 22 01"),
+            Ok(HashSet::from([1_u32, 9, 10, 22]))
         );
     }
     #[test]
@@ -112,11 +105,12 @@ mod tests {
         //BallSet { red: 0, green: 2, blue: 0 }
         assert_eq!(
             DaySolution::parse_one_line(line),
-            Card {
+            Ok(Card {
                 no: 1,
                 numbers_win: HashSet::from([1_u32, 12]),
                 numbers_have: HashSet::from([1_u32, 9, 10, 22]),
-            }
-        )
+            })
+        );
+        assert!(DaySolution::parse_one_line("Card 1: 1 12 10").is_err());
     }
 }
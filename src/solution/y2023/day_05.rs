@@ -1,6 +1,7 @@
 use regex::Regex;
 use std::collections::HashMap;
 
+use super::{parse_number, ParseError};
 use crate::Part;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -15,6 +16,7 @@ struct Mapping {
     src_rng: Rng,
 }
 
+#[derive(Clone)]
 pub struct P {
     seeds: Vec<Rng>,
     maps: HashMap<String, Vec<Mapping>>,
@@ -23,41 +25,41 @@ pub struct P {
 pub struct DaySolution(P);
 
 impl DaySolution {
-    fn extract_seeds(part: Part, line: &str) -> Vec<Rng> {
+    fn extract_seeds(part: Part, input: &str, line: &str) -> Result<Vec<Rng>, ParseError> {
         match part {
             Part::One => Regex::new(r#"\d+"#)
                 .unwrap()
                 .captures_iter(line)
                 .map(|c| {
-                    let start = c.get(0).unwrap().as_str().parse::<u64>().unwrap();
+                    let start = parse_number(input, c.get(0).unwrap().as_str())?;
                     let len = 1;
-                    Rng { start, len }
+                    Ok(Rng { start, len })
                 })
                 .collect(),
             Part::Two => Regex::new(r#"(\d+) (\d+)"#)
                 .unwrap()
                 .captures_iter(line)
                 .map(|c| {
-                    let start = c.get(1).unwrap().as_str().parse::<u64>().unwrap();
-                    let len = c.get(2).unwrap().as_str().parse::<u64>().unwrap();
-                    Rng { start, len }
+                    let start = parse_number(input, c.get(1).unwrap().as_str())?;
+                    let len = parse_number(input, c.get(2).unwrap().as_str())?;
+                    Ok(Rng { start, len })
                 })
                 .collect(),
         }
     }
 
-    fn parse_one_mapping_line(line: &str) -> Mapping {
-        Regex::new(r#"(\d+) (\d+) (\d+)"#)
+    fn parse_one_mapping_line(input: &str, line: &str) -> Result<Mapping, ParseError> {
+        let c = Regex::new(r#"(\d+) (\d+) (\d+)"#)
             .unwrap()
             .captures(line)
-            .map(|c| Mapping {
-                dest_start: c.get(1).unwrap().as_str().parse::<u64>().unwrap(),
-                src_rng: Rng {
-                    start: c.get(2).unwrap().as_str().parse::<u64>().unwrap(),
-                    len: c.get(3).unwrap().as_str().parse::<u64>().unwrap(),
-                },
-            })
-            .expect(format!("Can't parse the string '{}' into mapping", line).as_str())
+            .ok_or_else(|| ParseError::at(input, line, "expected '<dest> <source> <length>'"))?;
+        Ok(Mapping {
+            dest_start: parse_number(input, c.get(1).unwrap().as_str())?,
+            src_rng: Rng {
+                start: parse_number(input, c.get(2).unwrap().as_str())?,
+                len: parse_number(input, c.get(3).unwrap().as_str())?,
+            },
+        })
     }
 
     fn fill_in_missing_mapping_ranges(mappings: Vec<Mapping>) -> Vec<Mapping> {
@@ -102,61 +104,72 @@ impl DaySolution {
             .collect()
     }
 
-    fn parse_mapping_lines(text: &str) -> Vec<Mapping> {
+    fn parse_mapping_lines(input: &str, text: &str) -> Result<Vec<Mapping>, ParseError> {
         let mappings: Vec<Mapping> = text
             .lines()
             .filter(|l| !l.is_empty())
-            .map(Self::parse_one_mapping_line)
-            .collect();
-        Self::fill_in_missing_mapping_ranges(mappings)
+            .map(|l| Self::parse_one_mapping_line(input, l))
+            .collect::<Result<_, _>>()?;
+        Ok(Self::fill_in_missing_mapping_ranges(mappings))
     }
 
-    fn parse_input(part: Part, text_input: String) -> P {
+    const MAP_NAMES: [&'static str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+
+    // find out why input does not match expected layout of maps
+    fn explain_maps_mismatch(text_input: &str) -> ParseError {
+        let last_line = text_input.lines().last().unwrap_or("");
+        let missing = Self::MAP_NAMES
+            .iter()
+            .find(|name| !text_input.contains(&format!("{} map:", name)));
+        match missing {
+            Some(name) => ParseError::at(
+                text_input,
+                last_line,
+                format!("missing section '{} map:'", name),
+            ),
+            None => {
+                let re_line = Regex::new(r#"^(seeds:[\d ]+|[a-z-]+ map:|[\d ]*)$"#).unwrap();
+                let line = text_input
+                    .lines()
+                    .find(|l| !re_line.is_match(l))
+                    .unwrap_or(last_line);
+                ParseError::at(text_input, line, "unexpected line in almanac")
+            }
+        }
+    }
+
+    fn parse_input(part: Part, text_input: String) -> Result<P, ParseError> {
         let re_seeds = Regex::new(r#"seeds: ([\d ])+"#).unwrap();
-        let seeds: Vec<Rng> = re_seeds
+        let seeds_line = re_seeds
             .captures(&text_input)
-            .map(|c| Self::extract_seeds(part, c.get(0).unwrap().as_str()))
-            .unwrap();
+            .map(|c| c.get(0).unwrap().as_str())
+            .ok_or_else(|| {
+                let first_line = text_input.lines().next().unwrap_or("");
+                ParseError::at(&text_input, first_line, "expected 'seeds: <numbers>'")
+            })?;
+        let seeds: Vec<Rng> = Self::extract_seeds(part, &text_input, seeds_line)?;
         let re_maps = Regex::new(r#"seed-to-soil map:([\d \n]*)soil-to-fertilizer map:([0-9 \n]*)fertilizer-to-water map:([0-9 \n]*)water-to-light map:([0-9 \n]*)light-to-temperature map:([0-9 \n]*)temperature-to-humidity map:([0-9 \n]*)humidity-to-location map:([0-9 \n]*)"#).unwrap();
-        let maps: HashMap<String, Vec<Mapping>> = re_maps
+        let c = re_maps
             .captures(&text_input)
-            .map(|c| {
-                [
-                    (
-                        "seed-to-soil",
-                        Self::parse_mapping_lines(c.get(1).unwrap().as_str()),
-                    ),
-                    (
-                        "soil-to-fertilizer",
-                        Self::parse_mapping_lines(c.get(2).unwrap().as_str()),
-                    ),
-                    (
-                        "fertilizer-to-water",
-                        Self::parse_mapping_lines(c.get(3).unwrap().as_str()),
-                    ),
-                    (
-                        "water-to-light",
-                        Self::parse_mapping_lines(c.get(4).unwrap().as_str()),
-                    ),
-                    (
-                        "light-to-temperature",
-                        Self::parse_mapping_lines(c.get(5).unwrap().as_str()),
-                    ),
-                    (
-                        "temperature-to-humidity",
-                        Self::parse_mapping_lines(c.get(6).unwrap().as_str()),
-                    ),
-                    (
-                        "humidity-to-location",
-                        Self::parse_mapping_lines(c.get(7).unwrap().as_str()),
-                    ),
-                ]
-            })
-            .unwrap()
+            .ok_or_else(|| Self::explain_maps_mismatch(&text_input))?;
+        let maps: HashMap<String, Vec<Mapping>> = Self::MAP_NAMES
             .iter()
-            .map(|(k, v)| (String::from(*k), v.clone()))
-            .collect();
-        P { seeds, maps }
+            .enumerate()
+            .map(|(i, name)| {
+                let mappings =
+                    Self::parse_mapping_lines(&text_input, c.get(i + 1).unwrap().as_str())?;
+                Ok((String::from(*name), mappings))
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(P { seeds, maps })
     }
 
     /*
@@ -234,15 +247,17 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+    const SHARED_PROBLEM: bool = false;
 
     type Answer = Option<u64>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         DaySolution::parse_input(Part::One, text_input)
     }
 
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
+    fn parse_input_part_2(text_input: String) -> Result<Self::Problem, ParseError> {
         DaySolution::parse_input(Part::Two, text_input)
     }
 
@@ -272,13 +287,6 @@ impl super::Solution for DaySolution {
             .map(|r| r.start)
             .min()
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
 
 #[cfg(test)]
@@ -296,7 +304,7 @@ mod tests {
         //BallSet { red: 0, green: 2, blue: 0 }
         assert_eq!(
             {
-                let mut l = DaySolution::parse_mapping_lines(line);
+                let mut l = DaySolution::parse_mapping_lines(line, line).unwrap();
                 l.sort_by(|a, b| a.src_rng.start.cmp(&b.src_rng.start));
                 l
             },
@@ -324,11 +332,27 @@ mod tests {
         )
     }
     #[test]
+    fn explain_maps_mismatch() {
+        let text = "seeds: 1 2\n\nseed-to-soil map:\n50 98 2\n52 5O 48\n";
+        let error = DaySolution::explain_maps_mismatch(text);
+        assert_eq!(error.message, "missing section 'soil-to-fertilizer map:'");
+        let text = DaySolution::MAP_NAMES
+            .iter()
+            .map(|name| format!("{} map:\n1 2 3\n", name))
+            .collect::<String>()
+            .replace("2 3", "2 x");
+        let error = DaySolution::explain_maps_mismatch(&text);
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 1, "1 2 x")
+        );
+    }
+    #[test]
     fn map_once() {
         let line = "50 98 2\n52 50 48";
         assert_eq!(
             {
-                let mut ms = DaySolution::parse_mapping_lines(line);
+                let mut ms = DaySolution::parse_mapping_lines(line, line).unwrap();
                 ms.sort_by(|a, b| a.src_rng.start.cmp(&b.src_rng.start));
                 DaySolution::map_once(&Rng { start: 95, len: 10 }, &ms)
             },
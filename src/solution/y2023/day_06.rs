@@ -1,5 +1,6 @@
 use regex::Regex;
 
+use super::{parse_number, ParseError};
 use crate::Part;
 
 type Time = u64;
@@ -15,48 +16,62 @@ pub type P = Vec<Race>;
 pub struct DaySolution(P);
 
 impl DaySolution {
-    fn parse_input(part: Part, text_input: String) -> P {
+    /// Numbers of the line for part 1, for part 2 the one number whose digits are separated by spaces.
+    /// The joined number is not a part of the input, so its error points to the numbers of the line.
+    fn parse_numbers(part: Part, input: &str, numbers: &str) -> Result<Vec<u64>, ParseError> {
+        match part {
+            Part::One => Regex::new(r#"\d+"#)
+                .unwrap()
+                .find_iter(numbers)
+                .map(|m| parse_number(input, m.as_str()))
+                .collect(),
+            Part::Two => {
+                let joined: String = numbers.split_whitespace().collect();
+                let number = joined.parse().map_err(|_| {
+                    let message = format!("'{joined}' is not a valid number");
+                    ParseError::at(input, numbers.trim(), message)
+                })?;
+                Ok(vec![number])
+            }
+        }
+    }
+
+    fn parse_input(part: Part, text_input: String) -> Result<P, ParseError> {
+        let first_line = text_input.lines().next().unwrap_or("");
         let time_text_ = Regex::new(r#"Time:([ \d]+)"#)
             .unwrap()
             .captures(&text_input)
-            .unwrap()
+            .ok_or_else(|| ParseError::at(&text_input, first_line, "expected 'Time: <numbers>'"))?
             .get(1)
             .unwrap()
             .as_str();
-        let time_text = match part {
-            Part::One => String::from(time_text_),
-            Part::Two => time_text_.split_whitespace().collect::<String>(),
-        };
-
-        let times: Vec<Time> = Regex::new(r#"\d+"#)
-            .unwrap()
-            .captures_iter(&time_text)
-            .map(|c| c.get(0).unwrap().as_str().parse::<Time>().unwrap())
-            .collect();
+        let times: Vec<Time> = DaySolution::parse_numbers(part, &text_input, time_text_)?;
 
         let dist_text_ = Regex::new(r#"Distance:([ \d]+)"#)
             .unwrap()
             .captures(&text_input)
-            .unwrap()
+            .ok_or_else(|| {
+                let line = text_input.lines().nth(1).unwrap_or(first_line);
+                ParseError::at(&text_input, line, "expected 'Distance: <numbers>'")
+            })?
             .get(1)
             .unwrap()
             .as_str();
-        let dist_text = match part {
-            Part::One => String::from(dist_text_),
-            Part::Two => dist_text_.split_whitespace().collect::<String>(),
-        };
-
-        let dists: Vec<Distance> = Regex::new(r#"\d+"#)
-            .unwrap()
-            .captures_iter(&dist_text)
-            .map(|c| c.get(0).unwrap().as_str().parse::<Time>().unwrap())
-            .collect();
+        let dists: Vec<Distance> = DaySolution::parse_numbers(part, &text_input, dist_text_)?;
+
+        if times.len() != dists.len() {
+            return Err(ParseError::at(
+                &text_input,
+                dist_text_,
+                format!("expected {} distances, found {}", times.len(), dists.len()),
+            ));
+        }
 
-        times
+        Ok(times
             .iter()
             .zip(dists.iter())
             .map(|(&time, &record)| Race { time, record })
-            .collect()
+            .collect())
     }
 
     fn _analytic_distance(race_time: &Time, press_time: &Time) -> Distance {
@@ -88,15 +103,17 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+    const SHARED_PROBLEM: bool = false;
 
     type Answer = Option<u64>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         DaySolution::parse_input(Part::One, text_input)
     }
 
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
+    fn parse_input_part_2(text_input: String) -> Result<Self::Problem, ParseError> {
         DaySolution::parse_input(Part::Two, text_input)
     }
 
@@ -115,13 +132,6 @@ impl super::Solution for DaySolution {
             .product();
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
 
 #[cfg(test)]
@@ -138,7 +148,7 @@ mod tests {
         let line = "Time:      7  15   30\nDistance:  9  40  200";
         assert_eq!(
             DaySolution::parse_input(Part::One, String::from(line)),
-            vec![
+            Ok(vec![
                 Race { time: 7, record: 9 },
                 Race {
                     time: 15,
@@ -148,15 +158,23 @@ mod tests {
                     time: 30,
                     record: 200
                 },
-            ]
+            ])
         );
         assert_eq!(
             DaySolution::parse_input(Part::Two, String::from(line)),
-            vec![Race {
+            Ok(vec![Race {
                 time: 71530,
                 record: 940200
-            }]
-        )
+            }])
+        );
+        assert_eq!(
+            DaySolution::parse_input(Part::One, String::from("Time: 7 15\nDistance: 9"))
+                .map_err(|e| e.line),
+            Err(2)
+        );
+        let overflow = "Time: 7\nDistance:  99999 99999 99999 99999 99999";
+        let error = DaySolution::parse_input(Part::Two, String::from(overflow)).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.len()), (2, 12, 29));
     }
 
     #[test]
@@ -2,6 +2,8 @@ use std::{cmp::Ordering, collections::HashMap};
 
 use regex::Regex;
 
+use super::{parse_number, ParseError};
+
 type Label = char;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -16,6 +18,7 @@ enum HandType {
 }
 
 type Bid = u32;
+#[derive(Clone)]
 pub struct Hand {
     cards: String,
     hand_type: HandType,
@@ -59,21 +62,19 @@ impl DaySolution {
     }
 
     // parse one line
-    fn parse_one_line(line: &str) -> Hand {
-        Regex::new(r#"^([AKQJT2-9]+) (\d+)$"#)
+    fn parse_one_line(line: &str) -> Result<Hand, ParseError> {
+        let c = Regex::new(r#"^([AKQJT2-9]+) (\d+)$"#)
             .unwrap()
             .captures(line)
-            .map(|c| {
-                let cards: String = String::from(c.get(1).unwrap().as_str());
-                let hand_type = Self::hand_type(&cards);
-                let bid: u32 = c.get(2).unwrap().as_str().parse::<u32>().unwrap();
-                Hand {
-                    cards,
-                    hand_type,
-                    bid,
-                }
-            })
-            .unwrap()
+            .ok_or_else(|| ParseError::at(line, line, "expected '<cards> <bid>'"))?;
+        let cards: String = String::from(c.get(1).unwrap().as_str());
+        let hand_type = Self::hand_type(&cards);
+        let bid: u32 = parse_number(line, c.get(2).unwrap().as_str())?;
+        Ok(Hand {
+            cards,
+            hand_type,
+            bid,
+        })
     }
 
     // convert label into number that can be compared
@@ -229,21 +230,19 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
 
     type Answer = Option<u32>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line)
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
-    }
-
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
         let mut hands = problem;
         hands.sort_by(|hand_1, hand_2| DaySolution::compare_hands(hand_2, hand_1, false));
@@ -265,13 +264,6 @@ impl super::Solution for DaySolution {
             .sum();
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
 
 #[cfg(test)]
@@ -294,6 +286,13 @@ mod tests {
         assert_eq!(DaySolution::hand_type_rank(HandType::HighCard), 6);
     }
 
+    #[test]
+    fn parse_one_line() {
+        let hand = DaySolution::parse_one_line("32T3K 765").unwrap();
+        assert_eq!((hand.cards.as_str(), hand.bid), ("32T3K", 765));
+        assert!(DaySolution::parse_one_line("32T3X 765").is_err());
+    }
+
     #[test]
     fn hand_type() {
         assert_eq!(DaySolution::hand_type("KKKKK"), HandType::FiveOfAKind);
@@ -1,3 +1,4 @@
+use super::ParseError;
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -6,30 +7,34 @@ type Letter = u8;
 type Location = [Letter; 3];
 type Network = HashMap<Location, (Location, Location)>;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 enum Turn {
     Left,
     Right,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct P {
     instructions: Vec<Turn>,
     network: Network,
 }
 
-pub struct Day(P);
+pub struct DaySolution(P);
 
-impl Day {
+impl DaySolution {
     const START: [u8; 3] = [b'A'; 3];
     const FINISH: [u8; 3] = [b'Z'; 3];
 
-    fn parse_instructions(line: &str) -> Vec<Turn> {
-        line.chars()
-            .map(|c| match c {
-                'L' => Turn::Left,
-                'R' => Turn::Right,
-                _ => panic!("Could not recognize instruction '{}'", c),
+    fn parse_instructions(line: &str) -> Result<Vec<Turn>, ParseError> {
+        line.char_indices()
+            .map(|(i, c)| match c {
+                'L' => Ok(Turn::Left),
+                'R' => Ok(Turn::Right),
+                _ => Err(ParseError::at(
+                    line,
+                    &line[i..i + c.len_utf8()],
+                    "could not recognize instruction",
+                )),
             })
             .collect()
     }
@@ -72,28 +77,42 @@ impl Day {
     }
 }
 
-impl super::Solution for Day {
+impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
-        Regex::new(r#"([LR]+)\n\n([\S\s]+)"#)
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
+        let c = Regex::new(r#"^(\S+)\n\n([\S\s]+)"#)
             .unwrap()
             .captures(&text_input)
-            .map(|c| {
-                let (_, [text_instructions, text_network]) = c.extract();
-                Self::Problem {
-                    instructions: Day::parse_instructions(text_instructions),
-                    network: Day::parse_network(text_network),
-                }
-            })
-            .unwrap()
-    }
-
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
+            .ok_or_else(|| {
+                let first_line = text_input.lines().next().unwrap_or("");
+                ParseError::at(
+                    &text_input,
+                    first_line,
+                    "expected instructions, empty line and network",
+                )
+            })?;
+        let (_, [text_instructions, text_network]) = c.extract();
+        let re_node = Regex::new(r#"^[\d\w]{3} = \([\d\w]{3}, [\d\w]{3}\)$"#).unwrap();
+        if let Some(line) = text_network
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .find(|l| !re_node.is_match(l))
+        {
+            return Err(ParseError::at(
+                &text_input,
+                line,
+                "expected node as 'AAA = (BBB, CCC)'",
+            ));
+        }
+        Ok(Self::Problem {
+            instructions: DaySolution::parse_instructions(text_instructions)?,
+            network: DaySolution::parse_network(text_network),
+        })
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
@@ -103,11 +122,11 @@ impl super::Solution for Day {
         } = problem;
         let run_length = instructions.len() as u32;
         let number_of_runs: usize = (0..)
-            .scan(Day::START, |position, _| {
+            .scan(DaySolution::START, |position, _| {
                 // make one run trhough network and update the position
-                *position = Day::run_network_once(*position, &instructions, &network);
+                *position = DaySolution::run_network_once(*position, &instructions, &network);
                 match *position {
-                    Day::FINISH => None,
+                    DaySolution::FINISH => None,
                     _ => Some(*position),
                 }
             })
@@ -134,7 +153,7 @@ impl super::Solution for Day {
                     .cycle()
                     .scan(*l, |position, instruction| {
                         // make one run trhough network and update the position
-                        *position = Day::go_to_new_location(position, instruction, &network);
+                        *position = DaySolution::go_to_new_location(position, instruction, &network);
                         match position[2] == b'Z' {
                             true => None,
                             _ => Some(*position),
@@ -143,26 +162,20 @@ impl super::Solution for Day {
                     .count()
             })
             .map(|r| r + 1)
-            .fold(1, |z, x| Day::lcm(z, x));
+            .fold(1, |z, x| DaySolution::lcm(z, x));
 
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::Solution;
     use super::*;
     #[test]
     fn parse_network() {
         assert_eq!(
-            Day::parse_network("AAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)"),
+            DaySolution::parse_network("AAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)"),
             HashMap::from([
                 ([b'Z'; 3], ([b'Z'; 3], [b'Z'; 3])),
                 ([b'B'; 3], ([b'A'; 3], [b'Z'; 3])),
@@ -170,7 +183,7 @@ mod tests {
             ])
         );
         assert_eq!(
-            Day::parse_network("ABC = (DEF, HIJ)\nBBB = (AAA, ZZZ)"),
+            DaySolution::parse_network("ABC = (DEF, HIJ)\nBBB = (AAA, ZZZ)"),
             HashMap::from([
                 ([b'B'; 3], ([b'A'; 3], [b'Z'; 3])),
                 ([b'A', b'B', b'C'], ([b'D', b'E', b'F'], [b'H', b'I', b'J'])),
@@ -178,14 +191,23 @@ mod tests {
         );
     }
     #[test]
+    fn parse_input() {
+        let input = String::from("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ\nZZZ = (ZZZ, ZZZ)");
+        let error = DaySolution::parse_input_part_1(input).unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (4, "BBB = (AAA, ZZZ"));
+        let input = String::from("LRX\n\nAAA = (BBB, BBB)");
+        let error = DaySolution::parse_input_part_1(input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+    }
+    #[test]
     fn go_to_new_location() {
         let (aaa, bbb, zzz) = ([b'A'; 3], [b'B'; 3], [b'Z'; 3]);
-        let network = Day::parse_network("AAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)");
-        assert_eq!(Day::go_to_new_location(&aaa, &Turn::Left, &network), bbb);
-        assert_eq!(Day::go_to_new_location(&aaa, &Turn::Right, &network), bbb);
-        assert_eq!(Day::go_to_new_location(&bbb, &Turn::Left, &network), aaa);
-        assert_eq!(Day::go_to_new_location(&bbb, &Turn::Right, &network), zzz);
-        assert_eq!(Day::go_to_new_location(&zzz, &Turn::Right, &network), zzz);
-        assert_eq!(Day::go_to_new_location(&zzz, &Turn::Left, &network), zzz);
+        let network = DaySolution::parse_network("AAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)");
+        assert_eq!(DaySolution::go_to_new_location(&aaa, &Turn::Left, &network), bbb);
+        assert_eq!(DaySolution::go_to_new_location(&aaa, &Turn::Right, &network), bbb);
+        assert_eq!(DaySolution::go_to_new_location(&bbb, &Turn::Left, &network), aaa);
+        assert_eq!(DaySolution::go_to_new_location(&bbb, &Turn::Right, &network), zzz);
+        assert_eq!(DaySolution::go_to_new_location(&zzz, &Turn::Right, &network), zzz);
+        assert_eq!(DaySolution::go_to_new_location(&zzz, &Turn::Left, &network), zzz);
     }
 }
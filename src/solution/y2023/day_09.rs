@@ -1,4 +1,4 @@
-use regex::Regex;
+use super::{parse_number, ParseError};
 
 type Number = i64;
 type Series = Vec<Number>;
@@ -13,15 +13,9 @@ type P = Report;
 pub struct DaySolution(P);
 
 impl DaySolution {
-    fn parse_one_line(line: &str) -> Series {
-        Regex::new(r#"-?\d+"#)
-            .unwrap()
-            .captures_iter(line)
-            .map(|c| {
-                let num = c.get(0).unwrap().as_str();
-                num.parse::<Number>()
-                    .expect(format!("Couldn't parse value '{}' into i32", num).as_str())
-            })
+    fn parse_one_line(line: &str) -> Result<Series, ParseError> {
+        line.split_whitespace()
+            .map(|num| parse_number(line, num))
             .collect()
     }
 
@@ -82,21 +76,19 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
 
     type Answer = Option<Number>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line)
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(text_input)
-    }
-
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
         let answer = problem
             .iter()
@@ -112,13 +104,6 @@ impl super::Solution for DaySolution {
             .sum();
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
 
 #[cfg(test)]
@@ -128,8 +113,12 @@ mod tests {
     use super::DaySolution as DS;
     #[test]
     fn parse_one_line() {
-        assert_eq!(DS::parse_one_line("1 2 3 -3"), vec![1, 2, 3, -3]);
-        assert_eq!(DS::parse_one_line("-21 2 23"), vec![-21, 2, 23]);
+        assert_eq!(DS::parse_one_line("1 2 3 -3"), Ok(vec![1, 2, 3, -3]));
+        assert_eq!(DS::parse_one_line("-21 2 23"), Ok(vec![-21, 2, 23]));
+        assert_eq!(
+            DS::parse_one_line("-21 2x 23").map_err(|e| e.column),
+            Err(5)
+        );
     }
 
     #[test]
@@ -1,3 +1,5 @@
+use super::ParseError;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Location {
     r: usize,
@@ -25,6 +27,7 @@ struct State {
     lcn: Location,
     dir: Direction,
 }
+#[derive(Clone)]
 pub struct PipeMap {
     size: Location,
     segments: Vec<Vec<Segment>>,
@@ -35,17 +38,17 @@ type P = PipeMap;
 pub struct DaySolution(P);
 
 impl DaySolution {
-    fn parse_byte(b: u8) -> Segment {
+    fn parse_byte(b: u8) -> Option<Segment> {
         match b {
-            b'|' => Segment::NS,
-            b'-' => Segment::EW,
-            b'L' => Segment::NE,
-            b'J' => Segment::NW,
-            b'F' => Segment::SE,
-            b'7' => Segment::SW,
-            b'.' => Segment::O,
-            b'S' => Segment::S,
-            _ => panic!("could't parse '{}'", b),
+            b'|' => Some(Segment::NS),
+            b'-' => Some(Segment::EW),
+            b'L' => Some(Segment::NE),
+            b'J' => Some(Segment::NW),
+            b'F' => Some(Segment::SE),
+            b'7' => Some(Segment::SW),
+            b'.' => Some(Segment::O),
+            b'S' => Some(Segment::S),
+            _ => None,
         }
     }
     /*
@@ -132,28 +135,37 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         let segments: Vec<Vec<Segment>> = text_input
             .lines()
             .map(|l| {
-                l.bytes()
-                    .map(|b| DaySolution::parse_byte(b))
-                    .collect::<Vec<Segment>>()
+                l.char_indices()
+                    .map(|(i, c)| {
+                        let tile = &l[i..i + c.len_utf8()];
+                        DaySolution::parse_byte(tile.as_bytes()[0])
+                            .ok_or_else(|| ParseError::at(&text_input, tile, "unexpected tile"))
+                    })
+                    .collect::<Result<Vec<Segment>, ParseError>>()
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
+        if !text_input.contains('S') {
+            let first_line = text_input.lines().next().unwrap_or("");
+            return Err(ParseError::at(
+                &text_input,
+                first_line,
+                "start tile 'S' is missing",
+            ));
+        }
         let size = Location {
             r: segments.len(),
             c: segments[0].len(),
         };
-        PipeMap { size, segments }
-    }
-
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(text_input)
+        Ok(PipeMap { size, segments })
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
@@ -251,11 +263,4 @@ impl super::Solution for DaySolution {
         */
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
@@ -1,5 +1,7 @@
 use std::cmp::Ordering;
 
+use super::ParseError;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 struct Location(usize, usize);
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -13,11 +15,16 @@ type P = Picture;
 pub struct DaySolution(P);
 
 impl DaySolution {
-    fn parse_one_line(line: &str) -> Vec<Body> {
-        line.chars()
-            .map(|c| match c {
-                '#' => Body::Galaxy,
-                _ => Body::Void,
+    fn parse_one_line(line: &str) -> Result<Vec<Body>, ParseError> {
+        line.char_indices()
+            .map(|(i, c)| match c {
+                '#' => Ok(Body::Galaxy),
+                '.' => Ok(Body::Void),
+                _ => Err(ParseError::at(
+                    line,
+                    &line[i..i + c.len_utf8()],
+                    "unexpected symbol",
+                )),
             })
             .collect()
     }
@@ -112,21 +119,19 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line)
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
-    }
-
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
         DaySolution::solve(problem, 1)
     }
@@ -134,11 +139,4 @@ impl super::Solution for DaySolution {
     fn solve_part_2(problem: Self::Problem) -> Self::Answer {
         DaySolution::solve(problem, 1_000_000 - 1)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
@@ -1,7 +1,8 @@
 use rayon::prelude::*;
-use regex::Regex;
 use std::collections::HashMap;
 
+use super::{parse_number, ParseError};
+
 const S_W: u8 = b'.';
 const S_B: u8 = b'#';
 const S_U: u8 = b'?';
@@ -10,7 +11,7 @@ type Springs = Vec<u8>;
 // memory is a cache for current positions, number of rem indexes and associated number of solutions
 type Memory = HashMap<(usize, usize), usize>;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Record {
     springs: Springs,
     brokens: Vec<usize>,
@@ -21,31 +22,35 @@ type P = Vec<Record>;
 pub struct DaySolution(P);
 
 impl DaySolution {
-    fn parse_one_line(line: &str) -> Record {
-        let springs: Springs = Regex::new(r#"[?#\.]+"#)
-            .unwrap()
-            .captures(line)
-            .map(|c| c.get(0).unwrap().as_str())
-            .unwrap()
-            .chars()
-            .map(|c| match c {
-                '.' => S_W,
-                '#' => S_B,
-                '?' => S_U,
-                _ => panic!("Couldn't recognize char '{}'", c),
+    fn parse_one_line(line: &str) -> Result<Record, ParseError> {
+        let (springs_text, brokens_text) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(line, line, "expected '<springs> <groups>'"))?;
+        let springs: Springs = springs_text
+            .char_indices()
+            .map(|(i, c)| match c {
+                '.' => Ok(S_W),
+                '#' => Ok(S_B),
+                '?' => Ok(S_U),
+                _ => Err(ParseError::at(
+                    line,
+                    &springs_text[i..i + c.len_utf8()],
+                    "couldn't recognize spring",
+                )),
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        let brokens = Regex::new(r#"\d+"#)
-            .unwrap()
-            .captures_iter(line)
-            .map(|c| c.get(0).unwrap().as_str().parse::<usize>().unwrap())
-            .collect();
+        let brokens = brokens_text
+            .split(',')
+            .map(|n| parse_number(line, n))
+            .collect::<Result<_, _>>()?;
 
-        Record { springs, brokens }
+        Ok(Record { springs, brokens })
     }
 
-    fn parse_one_line_part_2(line: &str) -> Record {
+    fn parse_one_line_part_2(line: &str) -> Result<Record, ParseError> {
+        // validate the folded record first to report errors in original positions
+        Self::parse_one_line(line)?;
         let splits = String::from(line)
             .split(" ")
             .map(|x| String::from(x))
@@ -182,21 +187,27 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+    const SHARED_PROBLEM: bool = false;
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line)
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
+    fn parse_input_part_2(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line_part_2)
+            .enumerate()
+            .map(|(i, line)| {
+                DaySolution::parse_one_line_part_2(line).map_err(|e| e.offset_lines(i))
+            })
             .collect()
     }
 
@@ -212,13 +223,6 @@ impl super::Solution for DaySolution {
             .sum();
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
 
 #[cfg(test)]
@@ -229,20 +233,22 @@ mod tests {
     fn parse_one_line() {
         assert_eq!(
             DS::parse_one_line(".#? 1,1"),
-            Rec {
+            Ok(Rec {
                 springs: vec![S_W, S_B, S_U],
                 brokens: vec![1, 1]
-            }
-        )
+            })
+        );
+        assert_eq!(DS::parse_one_line(".#! 1,1").map_err(|e| e.column), Err(3));
+        assert_eq!(DS::parse_one_line(".#? 1,x").map_err(|e| e.column), Err(7));
     }
     #[test]
     fn parse_one_line_part_2() {
         assert_eq!(
             DS::parse_one_line_part_2(".# 1,2"),
-            Rec {
+            Ok(Rec {
                 springs: vec![S_W, S_B, S_U, S_W, S_B, S_U, S_W, S_B, S_U, S_W, S_B, S_U, S_W, S_B],
                 brokens: vec![1, 2, 1, 2, 1, 2, 1, 2, 1, 2]
-            }
+            })
         )
     }
 
@@ -250,29 +256,32 @@ mod tests {
     fn process_one_simple_record() {
         /*
             assert_eq!(
-                DS::process_one_record(&DS::parse_one_line("?? 1")),
+                DS::process_one_record(&DS::parse_one_line("?? 1").unwrap()),
                 2
             );
             assert_eq!(
-                DS::process_one_record(&DS::parse_one_line("??? 1,1")),
+                DS::process_one_record(&DS::parse_one_line("??? 1,1").unwrap()),
                 1
             );
             assert_eq!(
-                DS::process_one_record(&DS::parse_one_line("??#?? 2")),
+                DS::process_one_record(&DS::parse_one_line("??#?? 2").unwrap()),
                 2
             );
             assert_eq!(
-                DS::process_one_record(&DS::parse_one_line("?.?? 1,2")),
+                DS::process_one_record(&DS::parse_one_line("?.?? 1,2").unwrap()),
                 0
             );
         */
-        assert_eq!(DS::process_one_record(&DS::parse_one_line("...# 1")), 1);
+        assert_eq!(
+            DS::process_one_record(&DS::parse_one_line("...# 1").unwrap()),
+            1
+        );
     }
 
     #[test]
     fn process_one_heavy_record() {
         assert_eq!(
-            DS::process_one_record(&DS::parse_one_line("?#.??????#??#?#?#?#? 1,1,15")),
+            DS::process_one_record(&DS::parse_one_line("?#.??????#??#?#?#?#? 1,1,15").unwrap()),
             1_usize
         );
     }
@@ -280,7 +289,7 @@ mod tests {
     #[test]
     fn process_one_heavy_record_part_2() {
         assert_eq!(
-            DS::process_one_record(&DS::parse_one_line_part_2(".?????????? 2,2")),
+            DS::process_one_record(&DS::parse_one_line_part_2(".?????????? 2,2").unwrap()),
             111063614
         );
     }
@@ -288,27 +297,27 @@ mod tests {
     #[test]
     fn process_one_record() {
         assert_eq!(
-            DS::process_one_record(&DS::parse_one_line("???.### 1,1,3")),
+            DS::process_one_record(&DS::parse_one_line("???.### 1,1,3").unwrap()),
             1_usize
         );
         assert_eq!(
-            DS::process_one_record(&DS::parse_one_line(".??..??...?##. 1,1,3")),
+            DS::process_one_record(&DS::parse_one_line(".??..??...?##. 1,1,3").unwrap()),
             4_usize
         );
         assert_eq!(
-            DS::process_one_record(&DS::parse_one_line("?#?#?#?#?#?#?#? 1,3,1,6")),
+            DS::process_one_record(&DS::parse_one_line("?#?#?#?#?#?#?#? 1,3,1,6").unwrap()),
             1_usize
         );
         assert_eq!(
-            DS::process_one_record(&DS::parse_one_line("????.#...#... 4,1,1")),
+            DS::process_one_record(&DS::parse_one_line("????.#...#... 4,1,1").unwrap()),
             1_usize
         );
         assert_eq!(
-            DS::process_one_record(&DS::parse_one_line("????.######..#####. 1,6,5")),
+            DS::process_one_record(&DS::parse_one_line("????.######..#####. 1,6,5").unwrap()),
             4_usize
         );
         assert_eq!(
-            DS::process_one_record(&DS::parse_one_line("?###???????? 3,2,1")),
+            DS::process_one_record(&DS::parse_one_line("?###???????? 3,2,1").unwrap()),
             10_usize
         );
     }
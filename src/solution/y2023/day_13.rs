@@ -1,3 +1,4 @@
+use super::ParseError;
 use regex::Regex;
 type Pattern = Vec<Vec<u8>>;
 
@@ -41,13 +42,22 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 13;
+    const TITLE: &'static str = "Point of Incidence";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
+        let re_line = Regex::new(r#"^[.#]*$"#).unwrap();
+        if let Some(line) = text_input.lines().find(|l| !re_line.is_match(l)) {
+            return Err(ParseError::at(
+                &text_input,
+                line,
+                "expected only '.' and '#'",
+            ));
+        }
         let re_pattern = Regex::new(r#"([.#]+(\n[.#]+)+)"#).unwrap();
-        re_pattern
+        Ok(re_pattern
             .captures_iter(&text_input)
             .map(|c| {
                 c.get(0)
@@ -57,11 +67,7 @@ impl super::Solution for DaySolution {
                     .map(|line| DaySolution::parse_one_line(line))
                     .collect()
             })
-            .collect()
-    }
-
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
+            .collect::<Self::Problem>())
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
@@ -101,13 +107,6 @@ impl super::Solution for DaySolution {
             .sum();
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
 
 #[cfg(test)]
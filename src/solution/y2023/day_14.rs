@@ -1,3 +1,6 @@
+use super::ParseError;
+use crate::logging::trace;
+
 const BALL: u8 = b'O';
 const VOID: u8 = b'.';
 //const CUBE: u8 = b'#';
@@ -9,8 +12,15 @@ type P = Vec<Line>;
 pub struct DaySolution(P);
 
 impl DaySolution {
-    fn parse_one_line(line: &str) -> Line {
-        line.as_bytes().to_vec()
+    fn parse_one_line(line: &str) -> Result<Line, ParseError> {
+        match line.char_indices().find(|&(_, c)| !matches!(c, 'O' | '.' | '#')) {
+            Some((i, c)) => Err(ParseError::at(
+                line,
+                &line[i..i + c.len_utf8()],
+                "unexpected symbol",
+            )),
+            None => Ok(line.as_bytes().to_vec()),
+        }
     }
 
     fn transpose(mx: &Vec<Vec<u8>>) -> Vec<Line> {
@@ -95,21 +105,19 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(|line| DaySolution::parse_one_line(line))
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
-    }
-
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
         let v_arrangement = DaySolution::transpose(&problem);
         let answer = v_arrangement
@@ -124,18 +132,11 @@ impl super::Solution for DaySolution {
         let _a = (0..150_usize).fold(problem, |z, x| {
             let p1 = DaySolution::tilt_platform_cycle(&z);
             let l = DaySolution::north_load(&p1);
-            println!("iteration {x:>3}, load = {l:>5}");
+            trace!("iteration {x:>3}, load = {l:>5}");
             p1
         });
 
         /* according to ouptut: 112+1 first repitition, period = 7 */
         Some(((1_000_000_000) - (112 + 1)) % 7 + 112)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
@@ -1,5 +1,7 @@
 use std::fmt;
 
+use super::{parse_number, ParseError};
+
 type Hash = u8;
 #[derive(Clone, Debug)]
 struct Lens {
@@ -16,7 +18,7 @@ enum Do {
 struct Box(Vec<Lens>);
 type Boxes = Vec<Box>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Instruction {
     encoded: String,
     operation: Do,
@@ -25,26 +27,28 @@ type P = Vec<Instruction>;
 
 impl Instruction {
     // parse instruction from text
-    fn from(encoded: &String) -> Self {
+    fn from(input: &str, encoded: &str) -> Result<Self, ParseError> {
         let operation = if let Some('-') = encoded.chars().last() {
             let label = encoded.split("-").collect::<Vec<&str>>();
             Do::Pull {
                 label: String::from(label[0]),
             }
         } else {
-            let parts = encoded.split("=").collect::<Vec<&str>>();
+            let (label, focal) = encoded.split_once("=").ok_or_else(|| {
+                ParseError::at(input, encoded, "expected '<label>-' or '<label>=<focal>'")
+            })?;
             Do::Push {
-                label: String::from(parts[0]),
-                focal: parts[1].parse::<u8>().unwrap(),
+                label: String::from(label),
+                focal: parse_number(input, focal)?,
             }
         };
 
         let i = Self {
-            encoded: encoded.clone(),
+            encoded: String::from(encoded),
             operation,
         };
         //println!("instruction: {:?}", &i);
-        i
+        Ok(i)
     }
 }
 
@@ -145,21 +149,20 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 15;
+    const TITLE: &'static str = "Lens Library";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
+        // newline characters are not part of the sequence
         text_input
+            .trim_end()
             .split(",")
-            .map(|x| Instruction::from(&(String::from(x))))
+            .map(|x| Instruction::from(&text_input, x))
             .collect()
     }
 
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(text_input)
-    }
-
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
         let answer = problem
             .iter()
@@ -200,11 +203,4 @@ impl super::Solution for DaySolution {
             .sum();
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
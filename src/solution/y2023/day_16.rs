@@ -1,5 +1,9 @@
 use std::collections::HashSet;
 
+use super::ParseError;
+use crate::logging::{self, debug};
+use crate::Logging;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Direction {
     N,
@@ -112,10 +116,10 @@ impl DaySolution {
         for l in locations {
             print_area[l.0 as usize][l.1 as usize] = fill;
         }
-        println!("Energised locations ({} items):", locations.len());
+        debug!("Energised locations ({} items):", locations.len());
         print_area
             .iter()
-            .for_each(|r| println!("{}", r.iter().collect::<String>()));
+            .for_each(|r| debug!("{}", r.iter().collect::<String>()));
     }
 
     fn iterate(
@@ -155,21 +159,32 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
+        if let Some(i) = text_input.find(|c| !matches!(c, '.' | '/' | '\\' | '|' | '-' | '\n')) {
+            return Err(ParseError::at(
+                &text_input,
+                &text_input[i..i + 1],
+                "unexpected tile",
+            ));
+        }
         let data: Vec<Vec<u8>> = text_input
             .lines()
             .map(|l| l.as_bytes().iter().map(|x| *x).collect())
             .collect();
+        if data.is_empty() {
+            return Err(ParseError::at(
+                &text_input,
+                &text_input,
+                "contraption is empty",
+            ));
+        }
         let size: (usize, usize) = (data.len(), data[0].len());
-        Contraption { size, data }
-    }
-
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(text_input)
+        Ok(Contraption { size, data })
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
@@ -187,7 +202,7 @@ impl super::Solution for DaySolution {
         let answer = locations.len();
 
         // print locations
-        if false {
+        if logging::enabled(Logging::Debug) {
             let vec_locations: Vec<Location> =
                 locations.clone().iter().map(|x| x.clone()).collect();
             DaySolution::visualise_locations(&vec_locations);
@@ -212,11 +227,4 @@ impl super::Solution for DaySolution {
             })
             .max()
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
@@ -1,5 +1,9 @@
 use std::collections::HashMap;
 
+use super::ParseError;
+use crate::logging::trace;
+use crate::Part;
+
 type Dir = u8;
 
 const DIR_N: u8 = b'N';
@@ -38,8 +42,18 @@ type P = CityMap;
 pub struct DaySolution(P);
 
 impl DaySolution {
-    fn parse_one_line(line: &str) -> Vec<HeatLoss> {
-        line.chars().map(|c| c.to_digit(10).unwrap()).collect()
+    fn parse_one_line(line: &str) -> Result<Vec<HeatLoss>, ParseError> {
+        line.char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10).ok_or_else(|| {
+                    ParseError::at(
+                        line,
+                        &line[i..i + c.len_utf8()],
+                        "heat loss must be a digit",
+                    )
+                })
+            })
+            .collect()
     }
 
     fn is_opposite_direction(d1: Dir, d2: Dir) -> bool {
@@ -160,7 +174,7 @@ impl DaySolution {
                 loc: a.loc,
                 dir: a.dir,
             });
-            println!(
+            trace!(
                 "map size: {:>6}, number of new positions: {:>8}",
                 mem.len(),
                 new_pos.len()
@@ -173,21 +187,20 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 17;
+    const TITLE: &'static str = "Clumsy Crucible";
+    const PARTS: &'static [Part] = &[Part::One];
 
     type Answer = Option<u32>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line)
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
-    }
-
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
         let init_mem: Memory = HashMap::new();
         let map_size = DaySolution::map_size(&problem);
@@ -236,11 +249,4 @@ impl super::Solution for DaySolution {
         //Self::solve_part_1(_problem)
         None
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
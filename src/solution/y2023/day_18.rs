@@ -1,3 +1,4 @@
+use super::{parse_number, ParseError};
 use regex::Regex;
 
 type Direction = u8;
@@ -9,6 +10,7 @@ const DIR_R: Direction = b'R';
 const DIR_D: Direction = b'D';
 const DIR_L: Direction = b'L';
 
+#[derive(Clone)]
 pub struct Instruction {
     dir: Direction,
     len: i64,
@@ -139,59 +141,68 @@ impl Trench {
 }
 
 impl DaySolution {
-    fn parse_one_line_1(line: &str) -> Instruction {
+    fn parse_one_line_1(line: &str) -> Result<Instruction, ParseError> {
         let re = Regex::new(r#"(U|R|D|L) (\d+) \((#[[:xdigit:]]{6})\)"#).unwrap();
 
-        re.captures(line)
-            .map(|c| {
-                let (_, [dir, len, _]) = c.extract();
-                Instruction {
-                    dir: dir.as_bytes()[0],
-                    len: len.parse::<i64>().unwrap(),
-                }
-            })
-            .unwrap()
+        let c = re.captures(line).ok_or_else(|| {
+            ParseError::at(line, line, "expected '<U|R|D|L> <length> (#<color>)'")
+        })?;
+        let (_, [dir, len, _]) = c.extract();
+        Ok(Instruction {
+            dir: dir.as_bytes()[0],
+            len: parse_number(line, len)?,
+        })
     }
 
-    fn parse_one_line_2(line: &str) -> Instruction {
+    fn parse_one_line_2(line: &str) -> Result<Instruction, ParseError> {
         let re = Regex::new(r#"\(#([[:xdigit:]]{5})([[:xdigit:]]{1})\)"#).unwrap();
 
-        re.captures(line)
-            .map(|c| {
-                let (_, [len, dir]) = c.extract();
-                let dir = match dir {
-                    "0" => b'R',
-                    "1" => b'D',
-                    "2" => b'L',
-                    "3" => b'U',
-                    _ => unreachable!(),
-                };
-                Instruction {
+        let c = re
+            .captures(line)
+            .ok_or_else(|| ParseError::at(line, line, "expected '(#<length><direction>)'"))?;
+        let (_, [len, dir]) = c.extract();
+        let dir = match dir {
+            "0" => b'R',
+            "1" => b'D',
+            "2" => b'L',
+            "3" => b'U',
+            _ => {
+                return Err(ParseError::at(
+                    line,
                     dir,
-                    len: i64::from_str_radix(len, 16).unwrap(),
-                }
-            })
-            .unwrap()
+                    "direction must be one of 0, 1, 2, 3",
+                ))
+            }
+        };
+        Ok(Instruction {
+            dir,
+            // regex guarantees 5 hexadecimal digits which always fit into i64
+            len: i64::from_str_radix(len, 16).unwrap(),
+        })
     }
 }
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 18;
+    const TITLE: &'static str = "Lavaduct Lagoon";
+    const SHARED_PROBLEM: bool = false;
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line_1)
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line_1(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
+    fn parse_input_part_2(text_input: String) -> Result<Self::Problem, ParseError> {
         text_input
             .lines()
-            .map(DaySolution::parse_one_line_2)
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line_2(line).map_err(|e| e.offset_lines(i)))
             .collect()
     }
 
@@ -206,11 +217,4 @@ impl super::Solution for DaySolution {
         let answer = Trench::build(problem).area();
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!("\x1b[93mError\x1b[0m"),
-        }
-    }
 }
@@ -1,3 +1,4 @@
+use super::{parse_number, ParseError};
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -49,13 +50,14 @@ enum Comparison {
     Gt,
 }
 
+#[derive(Clone)]
 pub struct P {
     flows: HashMap<String, Workflow>,
     parts: Vec<Part>,
 }
 
 impl DaySolution {
-    fn parse_one_rule(line: &str) -> Rule {
+    fn parse_one_rule(input: &str, line: &str) -> Result<Rule, ParseError> {
         let re_condition = Regex::new(r#"^([xmas])([<>])(\d+):([AR]|[a-z]+)$"#).unwrap();
         let re_decision = Regex::new(r#"^([AR]|[a-z]+)$"#).unwrap();
 
@@ -66,39 +68,31 @@ impl DaySolution {
                 _ => Decision::SendTo(String::from(dec)),
             }
         }
-        let condition = re_condition.captures(line).map(|c| match c.get(0) {
-            Some(_) => {
-                let (_, [par, cmp, val, dec]) = c.extract();
-                let c = Condition {
-                    par: par.chars().nth(0).unwrap(),
-                    cmp: if cmp == "<" {
-                        Comparison::Lt
-                    } else {
-                        Comparison::Gt
-                    },
-                    val: val.parse::<usize>().unwrap(),
-                    dec: match_decision(dec),
-                };
-                Some(c)
-            }
-            _ => None,
-        });
-        let decision = re_decision.captures(line).map(|c| match c.get(0) {
-            Some(_) => {
-                let (_, [dec]) = c.extract();
-                Some(match_decision(dec))
-            }
-            _ => None,
-        });
-
-        if condition.is_some() {
-            Rule::Condition(condition.unwrap().unwrap())
+        if let Some(c) = re_condition.captures(line) {
+            let (_, [par, cmp, val, dec]) = c.extract();
+            Ok(Rule::Condition(Condition {
+                par: par.chars().nth(0).unwrap(),
+                cmp: if cmp == "<" {
+                    Comparison::Lt
+                } else {
+                    Comparison::Gt
+                },
+                val: parse_number(input, val)?,
+                dec: match_decision(dec),
+            }))
+        } else if let Some(c) = re_decision.captures(line) {
+            let (_, [dec]) = c.extract();
+            Ok(Rule::Decision(match_decision(dec)))
         } else {
-            Rule::Decision(decision.unwrap().unwrap())
+            Err(ParseError::at(
+                input,
+                line,
+                "expected rule as '<xmas><<|>><value>:<target>' or '<target>'",
+            ))
         }
     }
-    fn parse_one_workflow(line: &str) -> Workflow {
-        let main_re = Regex::new(r#"(\w+)\{([0-9a-zAR<>:,]+)\}"#).unwrap();
+    fn parse_one_workflow(input: &str, line: &str) -> Result<Workflow, ParseError> {
+        let main_re = Regex::new(r#"^(\w+)\{([0-9a-zAR<>:,]+)\}$"#).unwrap();
         let rules_re = Regex::new(r#"[0-9a-zAR<>:]+"#).unwrap();
         let (name, rules_str) = main_re
             .captures(line)
@@ -106,30 +100,29 @@ impl DaySolution {
                 let (_, [name, rules]) = c.extract();
                 (String::from(name), rules)
             })
-            .unwrap();
+            .ok_or_else(|| ParseError::at(input, line, "expected workflow as 'name{rules}'"))?;
         let rules = rules_re
             .captures_iter(rules_str)
             .map(|c| {
                 let rule = c.get(0).unwrap().as_str();
-                Self::parse_one_rule(rule)
+                Self::parse_one_rule(input, rule)
             })
-            .collect::<Vec<Rule>>();
-        Workflow { name, rules }
+            .collect::<Result<Vec<Rule>, ParseError>>()?;
+        Ok(Workflow { name, rules })
     }
 
-    fn parse_one_part(line: &str) -> Part {
-        let re = Regex::new(r#"\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}"#).unwrap();
-        re.captures(line)
-            .map(|c| {
-                let (_, [x, m, a, s]) = c.extract();
-                Part {
-                    x: x.parse::<usize>().unwrap(),
-                    m: m.parse::<usize>().unwrap(),
-                    a: a.parse::<usize>().unwrap(),
-                    s: s.parse::<usize>().unwrap(),
-                }
-            })
-            .unwrap()
+    fn parse_one_part(input: &str, line: &str) -> Result<Part, ParseError> {
+        let re = Regex::new(r#"^\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}$"#).unwrap();
+        let c = re.captures(line).ok_or_else(|| {
+            ParseError::at(input, line, "expected part as '{x=..,m=..,a=..,s=..}'")
+        })?;
+        let (_, [x, m, a, s]) = c.extract();
+        Ok(Part {
+            x: parse_number(input, x)?,
+            m: parse_number(input, m)?,
+            a: parse_number(input, a)?,
+            s: parse_number(input, s)?,
+        })
     }
 
     fn classify_one_part(
@@ -351,28 +344,30 @@ pub struct DaySolution(P);
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 19;
+    const TITLE: &'static str = "Aplenty";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
-        let v: Vec<&str> = text_input.split("\n\n").collect();
-        let flows_str = v[0];
-        let parts_str = v[1];
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
+        let (flows_str, parts_str) = text_input.split_once("\n\n").ok_or_else(|| {
+            let last_line = text_input.lines().last().unwrap_or("");
+            ParseError::at(
+                &text_input,
+                last_line,
+                "expected workflows and parts separated by empty line",
+            )
+        })?;
         let parts: Vec<Part> = parts_str
             .lines()
-            .map(|line| DaySolution::parse_one_part(line))
-            .collect();
+            .map(|line| DaySolution::parse_one_part(&text_input, line))
+            .collect::<Result<_, _>>()?;
         let flows: HashMap<String, Workflow> = flows_str
             .lines()
-            .map(|line| DaySolution::parse_one_workflow(line))
-            .map(|wfw| (wfw.name.clone(), wfw))
-            .collect();
-        P { flows, parts }
-    }
-
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(text_input)
+            .map(|line| DaySolution::parse_one_workflow(&text_input, line))
+            .map(|wfw| wfw.map(|wfw| (wfw.name.clone(), wfw)))
+            .collect::<Result<_, _>>()?;
+        Ok(P { flows, parts })
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
@@ -403,11 +398,4 @@ impl super::Solution for DaySolution {
         let answer = DaySolution::find_all_combinations(&part, &start, &flows);
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
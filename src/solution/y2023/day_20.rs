@@ -5,6 +5,10 @@ use std::{
 
 use regex::Regex;
 
+use super::ParseError;
+use crate::logging::{self, debug, trace};
+use crate::Logging;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Signal {
     Lo,
@@ -65,7 +69,10 @@ pub struct DaySolution(P);
 
 impl DaySolution {
     // parse one input line with relation to module
-    fn parse_one_line_module(line: &str, cbl_map: &HashMap<Label, Vec<Label>>) -> Module {
+    fn parse_one_line_module(
+        line: &str,
+        cbl_map: &HashMap<Label, Vec<Label>>,
+    ) -> Result<Module, ParseError> {
         let cables: Vec<Cable> = cbl_map
             .iter()
             .flat_map(|(from, tos)| {
@@ -75,57 +82,61 @@ impl DaySolution {
                 })
             })
             .collect();
-        Regex::new(r#"^([%&]?)([a-z]+) -> "#)
+        let c = Regex::new(r#"^([%&]?)([a-z]+) -> "#)
             .unwrap()
             .captures(line)
-            .map(|c| {
-                let (_, [module_type, name]) = c.extract();
-                //println!("Module type: '{}', module name = '{}'", module_type, name);
-                match (name, module_type) {
-                    (_, "%") => Module::FlipFlop {
-                        name: String::from(name),
-                        state: State::Off,
-                        input: Signal::Lo,
-                    },
-                    (_, "&") => Module::Conjunction {
-                        name: String::from(name),
-                        // rewrite to use HashMap<from, HashSet<to>>
-                        inputs: cables
-                            .iter()
-                            .filter(|c| c.to == name)
-                            .map(|c| (c.from.clone(), Signal::Lo))
-                            .collect::<HashMap<Label, Signal>>(),
-                    },
-                    ("broadcaster", _) => Module::Broadcaster {
-                        name: String::from("broadcaster"),
-                        input: Signal::Lo,
-                    },
-                    _ => unreachable!(),
-                }
-            })
-            .unwrap()
+            .ok_or_else(|| ParseError::at(line, line, "expected '[%&]<name> -> <names>'"))?;
+        let (_, [module_type, name]) = c.extract();
+        //println!("Module type: '{}', module name = '{}'", module_type, name);
+        let module = match (name, module_type) {
+            (_, "%") => Module::FlipFlop {
+                name: String::from(name),
+                state: State::Off,
+                input: Signal::Lo,
+            },
+            (_, "&") => Module::Conjunction {
+                name: String::from(name),
+                // rewrite to use HashMap<from, HashSet<to>>
+                inputs: cables
+                    .iter()
+                    .filter(|c| c.to == name)
+                    .map(|c| (c.from.clone(), Signal::Lo))
+                    .collect::<HashMap<Label, Signal>>(),
+            },
+            ("broadcaster", _) => Module::Broadcaster {
+                name: String::from("broadcaster"),
+                input: Signal::Lo,
+            },
+            _ => {
+                return Err(ParseError::at(
+                    line,
+                    name,
+                    "module without '%' or '&' type must be 'broadcaster'",
+                ))
+            }
+        };
+        Ok(module)
     }
     // parse the input line with relation to cables that connect modules
-    fn parse_one_line_cable(line: &str) -> (Label, Vec<Label>) {
-        let parts = line
-            .split(" -> ")
-            //.map(|s| String::from(s))
-            .collect::<Vec<&str>>();
+    fn parse_one_line_cable(line: &str) -> Result<(Label, Vec<Label>), ParseError> {
+        let (from_str, to_str) = line
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::at(line, line, "expected '<name> -> <names>'"))?;
         let re_name = Regex::new(r#"\w+"#).unwrap();
         // from
         let from = re_name
-            .captures(parts[0])
+            .captures(from_str)
             .map(|c| c.get(0).unwrap().as_str())
             .map(String::from)
-            .unwrap();
+            .ok_or_else(|| ParseError::at(line, from_str, "module name is missing"))?;
         // to
         let to: Vec<Label> = re_name
-            .captures_iter(parts[1])
+            .captures_iter(to_str)
             .map(|c| c.get(0).unwrap().as_str())
             .map(String::from)
             .collect();
 
-        (from, to)
+        Ok((from, to))
     }
 
     fn push_button(network: Network, by_one: bool) -> (Network, Vec<Pulse>) {
@@ -359,19 +370,24 @@ impl Network {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 20;
+    const TITLE: &'static str = "Pulse Propagation";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         let mut cbl_map: HashMap<Label, Vec<Label>> = text_input
             .lines()
-            .map(DaySolution::parse_one_line_cable)
-            .collect();
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line_cable(line).map_err(|e| e.offset_lines(i)))
+            .collect::<Result<_, _>>()?;
         let mut modules = text_input
             .lines()
-            .map(|line| DaySolution::parse_one_line_module(line, &cbl_map))
-            .collect::<Vec<Module>>();
+            .enumerate()
+            .map(|(i, line)| {
+                DaySolution::parse_one_line_module(line, &cbl_map).map_err(|e| e.offset_lines(i))
+            })
+            .collect::<Result<Vec<Module>, _>>()?;
 
         modules.push(Module::Button {
             name: String::from("button"),
@@ -380,25 +396,21 @@ impl super::Solution for DaySolution {
 
         cbl_map.insert(String::from("button"), vec![String::from("broadcaster")]);
 
-        Network { modules, cbl_map }
-    }
-
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
+        Ok(Network { modules, cbl_map })
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
         let no_pulses: Vec<Pulse> = vec![];
         let network = problem;
         let n = 1000;
-        let debug = false;
+        let debug = logging::enabled(Logging::Trace);
         let (_, pulses) = (0..n).fold((network, no_pulses), |(network, mut pulses), n| {
             let (new_network, mut new_pulses) = DaySolution::push_button(network, true);
             // debugging
             if debug {
-                println!("Step {:>4} pulses:", n + 1);
+                trace!("Step {:>4} pulses:", n + 1);
                 new_pulses.iter().for_each(|p| {
-                    println!("{:<11} -{:?}-> {:>11}", p.cable.from, p.signal, p.cable.to)
+                    trace!("{:<11} -{:?}-> {:>11}", p.cable.from, p.signal, p.cable.to)
                 });
                 new_network
                     .modules
@@ -412,7 +424,7 @@ impl super::Solution for DaySolution {
                         } => true,
                         _ => false,
                     })
-                    .for_each(|m| println!("{:?}", m));
+                    .for_each(|m| trace!("{:?}", m));
                 new_network
                     .modules
                     .iter()
@@ -421,7 +433,7 @@ impl super::Solution for DaySolution {
                         Module::Conjunction { inputs: _, name: _ } => true,
                         _ => false,
                     })
-                    .for_each(|m| println!("{:?}", m));
+                    .for_each(|m| trace!("{:?}", m));
             }
 
             pulses.append(&mut new_pulses);
@@ -430,7 +442,7 @@ impl super::Solution for DaySolution {
         let signals: Vec<Signal> = pulses.into_iter().map(|pulse| pulse.signal).collect();
         let lo_cnt = signals.iter().filter(|&x| x == &Signal::Lo).count();
         let hi_cnt = signals.iter().filter(|&x| x == &Signal::Hi).count();
-        println!("Pushes count: {n:>4}, low signals: {lo_cnt:>6}, high signals: {hi_cnt:>6}");
+        debug!("Pushes count: {n:>4}, low signals: {lo_cnt:>6}, high signals: {hi_cnt:>6}");
         Some(lo_cnt * hi_cnt)
     }
 
@@ -468,11 +480,4 @@ impl super::Solution for DaySolution {
 
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
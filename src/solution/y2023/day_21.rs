@@ -1,6 +1,8 @@
-use super::day_09;
+use super::{day_09, ParseError};
+use crate::logging::debug;
 use std::collections::HashSet;
 
+#[derive(Clone)]
 enum Tile {
     Plot,
     Rock,
@@ -9,6 +11,7 @@ enum Tile {
 
 type Location = (i32, i32);
 
+#[derive(Clone)]
 pub struct Garden {
     plan: Vec<Vec<Tile>>,
     size: Location,
@@ -57,13 +60,17 @@ impl Garden {
 }
 
 impl DaySolution {
-    fn parse_one_line(line: &str) -> Vec<Tile> {
-        line.chars()
-            .map(|c| match c {
-                '.' => Tile::Plot,
-                'S' => Tile::Start,
-                '#' => Tile::Rock,
-                _ => unreachable!(),
+    fn parse_one_line(line: &str) -> Result<Vec<Tile>, ParseError> {
+        line.char_indices()
+            .map(|(i, c)| match c {
+                '.' => Ok(Tile::Plot),
+                'S' => Ok(Tile::Start),
+                '#' => Ok(Tile::Rock),
+                _ => Err(ParseError::at(
+                    line,
+                    &line[i..i + c.len_utf8()],
+                    "unexpected tile",
+                )),
             })
             .collect()
     }
@@ -73,31 +80,35 @@ pub struct DaySolution(P);
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 21;
+    const TITLE: &'static str = "Step Counter";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         let tiles: Vec<Vec<Tile>> = text_input
             .lines()
-            .map(DaySolution::parse_one_line)
-            .collect();
+            .enumerate()
+            .map(|(i, line)| DaySolution::parse_one_line(line).map_err(|e| e.offset_lines(i)))
+            .collect::<Result<_, _>>()?;
+        if !text_input.contains('S') {
+            let first_line = text_input.lines().next().unwrap_or("");
+            return Err(ParseError::at(
+                &text_input,
+                first_line,
+                "start tile 'S' is missing",
+            ));
+        }
         let (rows, cols) = if tiles.len() == 0 {
             (0_i32, 0_i32)
         } else {
             (tiles.len() as i32, tiles[0].len() as i32)
         };
-        Garden {
+        Ok(Garden {
             plan: tiles,
             size: (rows, cols),
             infinite: false,
-        }
-    }
-
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
-        let mut problem = Self::parse_input_part_1(text_input);
-        problem.infinite = true;
-        problem
+        })
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
@@ -126,7 +137,11 @@ impl super::Solution for DaySolution {
         //
         let total_steps = 26501365;
         let min_req_sequence = 131 * 2 + 65;
-        let garden = problem;
+        // the plan repeats in every direction in part 2
+        let garden = Garden {
+            infinite: true,
+            ..problem
+        };
 
         // first we solve problem for monimum required number of steps to get the sequence
         let start_location = garden.start();
@@ -144,7 +159,7 @@ impl super::Solution for DaySolution {
             .enumerate()
             .filter(|(idx, _)| (idx + 1) % 131 == 65)
             .map(|(_idx, n)| {
-                println!("{:>5}: {:>12}", _idx, n);
+                debug!("{:>5}: {:>12}", _idx, n);
                 n as i64
             })
             .collect();
@@ -164,11 +179,4 @@ impl super::Solution for DaySolution {
 
         Some(answer as usize)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
@@ -1,3 +1,4 @@
+use super::{parse_number, ParseError};
 use regex::Regex;
 use std::cmp::Ordering;
 
@@ -12,20 +13,17 @@ type P = Vec<Brick>;
 
 impl Brick {
     // parse the brick
-    fn from(line: &str) -> Self {
-        fn to_u32(str: &str) -> u32 {
-            str.parse().unwrap()
-        }
+    fn from(line: &str) -> Result<Self, ParseError> {
+        let to_u32 = |str: &str| -> Result<u32, ParseError> { parse_number(line, str) };
         let re = Regex::new(r#"(-?\d+),(-?\d+),(-?\d+)~(-?\d+),(-?\d+),(-?\d+)"#).unwrap();
-        re.captures(line)
-            .map(|c| {
-                let (_, [x0, y0, z0, x1, y1, z1]) = c.extract();
-                Brick {
-                    beg: (to_u32(x0), to_u32(y0), to_u32(z0)),
-                    end: (to_u32(x1), to_u32(y1), to_u32(z1)),
-                }
-            })
-            .unwrap()
+        let c = re
+            .captures(line)
+            .ok_or_else(|| ParseError::at(line, line, "expected brick as 'x,y,z~x,y,z'"))?;
+        let (_, [x0, y0, z0, x1, y1, z1]) = c.extract();
+        Ok(Brick {
+            beg: (to_u32(x0)?, to_u32(y0)?, to_u32(z0)?),
+            end: (to_u32(x1)?, to_u32(y1)?, to_u32(z1)?),
+        })
     }
     // validate if brick is on the ground
     fn on_the_ground(&self) -> bool {
@@ -130,16 +128,17 @@ pub struct DaySolution(P);
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 22;
+    const TITLE: &'static str = "Sand Slabs";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
-        text_input.lines().map(Brick::from).collect()
-    }
-
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(text_input)
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
+        text_input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Brick::from(line).map_err(|e| e.offset_lines(i)))
+            .collect()
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
@@ -188,11 +187,4 @@ impl super::Solution for DaySolution {
             .sum();
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
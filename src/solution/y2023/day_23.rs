@@ -3,6 +3,8 @@ use std::{
     collections::{HashMap, HashSet},
 };
 
+use super::ParseError;
+
 const TILE_TRAIL: u8 = b'.';
 const TILE_TREES: u8 = b'#';
 const TILE_SL_LT: u8 = b'<';
@@ -21,6 +23,7 @@ struct Path {
     trace: Vec<Location>,
 }
 
+#[derive(Clone)]
 pub struct HikingMap {
     tiles: Vec<Vec<Tile>>,
     size: Location,
@@ -288,7 +291,8 @@ impl HikingMap {
             TILE_SL_UP => (r - 1, c + 0),
             TILE_SL_RT => (r + 0, c + 1),
             TILE_SL_LT => (r + 0, c - 1),
-            _ => panic!("unexpected tile {} in location ({}, {})", tile, r, c),
+            // tiles are validated when input is parsed
+            _ => unreachable!("unexpected tile {} in location ({}, {})", tile, r, c),
         }
     }
 
@@ -300,25 +304,38 @@ impl HikingMap {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 23;
+    const TITLE: &'static str = "A Long Walk";
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         let tiles: Vec<Vec<Tile>> = text_input
             .lines()
-            .map(|l| l.as_bytes().iter().map(|b| *b).collect::<Vec<Tile>>())
-            .collect();
+            .map(|l| {
+                l.char_indices()
+                    .map(|(i, c)| match c as u8 {
+                        TILE_TRAIL | TILE_TREES | TILE_SL_LT | TILE_SL_RT | TILE_SL_UP
+                        | TILE_SL_DN
+                            if c.is_ascii() =>
+                        {
+                            Ok(c as u8)
+                        }
+                        _ => Err(ParseError::at(
+                            &text_input,
+                            &l[i..i + c.len_utf8()],
+                            "unexpected tile",
+                        )),
+                    })
+                    .collect::<Result<Vec<Tile>, ParseError>>()
+            })
+            .collect::<Result<_, _>>()?;
         let size = if tiles.len() == 0 {
             (0, 0)
         } else {
             (tiles.len(), tiles[0].len())
         };
-        HikingMap { tiles, size }
-    }
-
-    fn parse_input_part_2(text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(text_input)
+        Ok(HikingMap { tiles, size })
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
@@ -431,11 +448,4 @@ impl super::Solution for DaySolution {
 
         Some(answer)
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
@@ -1,6 +1,10 @@
 use num_rational::Ratio;
 use regex::Regex;
-use std::{fmt, iter::successors, str::FromStr};
+use std::{fmt, iter::successors};
+
+use super::{parse_number, ParseError};
+use crate::logging::{self, log, trace};
+use crate::{Answer, Logging};
 
 //const UNIT: Rational64 = Rational64::from(1_i64);
 //const UNIT: Rational64 = 1_i64.into();
@@ -71,7 +75,7 @@ impl Spiral {
 
     fn grow(&self) -> Option<Self> {
         let limit: i64 = 10_000;
-        let debug = false;
+        let debug = logging::enabled(Logging::Trace);
         let &Spiral {
             dx: odx,
             dy: ody,
@@ -94,14 +98,17 @@ impl Spiral {
         };
 
         if debug && segment_passed == 0 {
-            println!("new segment_length: {}", segment_length);
+            trace!("new segment_length: {}", segment_length);
         }
         if segment_length > limit {
-            // something is wrong
-            println!(
-                "Stop growing the spiral after reaching limit size: {}",
-                limit
-            );
+            // something is wrong, tell it once per spiral: only when its growing crosses the limit
+            if osl <= limit {
+                log!(
+                    Logging::Warning,
+                    "Stop growing the spiral after reaching limit size: {}",
+                    limit
+                );
+            }
             None
         } else {
             Some(Spiral {
@@ -117,26 +124,27 @@ impl Spiral {
 }
 
 impl Particle {
-    fn from(from_str: &str) -> Self {
-        fn to_r64(str: &str) -> R {
-            R::from_str(str).unwrap()
-        }
+    fn from(from_str: &str) -> Result<Self, ParseError> {
+        let to_r64 = |str: &str| -> Result<R, ParseError> { parse_number(from_str, str) };
         let re =
             Regex::new(r#"(-?\d+), +(-?\d+), +(-?\d+) +@ +(-?\d+), +(-?\d+), +(-?\d+)"#).unwrap();
-        re.captures(from_str)
-            .map(|c| {
-                let (_, [x0, y0, z0, dx, dy, dz]) = c.extract();
-                Particle {
-                    loc: Location(to_r64(x0), to_r64(y0), to_r64(z0)),
-                    vel: Velocity(to_r64(dx), to_r64(dy), to_r64(dz)),
-                }
-            })
-            .unwrap()
+        let c = re.captures(from_str).ok_or_else(|| {
+            ParseError::at(
+                from_str,
+                from_str,
+                "expected particle as 'x, y, z @ dx, dy, dz'",
+            )
+        })?;
+        let (_, [x0, y0, z0, dx, dy, dz]) = c.extract();
+        Ok(Particle {
+            loc: Location(to_r64(x0)?, to_r64(y0)?, to_r64(z0)?),
+            vel: Velocity(to_r64(dx)?, to_r64(dy)?, to_r64(dz)?),
+        })
     }
 
     // port
     fn hits(&self, other: &Self) -> bool {
-        let debug = false;
+        let debug = logging::enabled(Logging::Trace);
         let unit = R::from(1);
         let zero = unit * 0;
         let Location(x0, y0, z0) = self.loc;
@@ -171,7 +179,7 @@ impl Particle {
                 (z0i - z0) / (vz - vzi)
             };
             if debug {
-                println!("Collision times: tx = {tx}, ty = {ty}, tz = {tz}");
+                trace!("Collision times: tx = {tx}, ty = {ty}, tz = {tz}");
             }
             //
             (tx == ty || tx == zero || ty == zero)
@@ -190,9 +198,9 @@ impl Particle {
     fn trj_cross_on_xy_with(&self, other_particle: &Particle) -> Option<Cross> {
         let zero = R::from(0);
         let that = other_particle;
-        let debug = false;
+        let debug = logging::enabled(Logging::Trace);
         if debug {
-            println!("Collide {} with {}", self, that);
+            trace!("Collide {} with {}", self, that);
         }
         let a = vec![self.vel.0, -that.vel.0, self.vel.1, -that.vel.1];
         let b = vec![that.loc.0 - self.loc.0, that.loc.1 - self.loc.1];
@@ -208,7 +216,7 @@ impl Particle {
             let time_2 = det_1 / det_m;
             let location = self.location_at(&time_1);
             if debug {
-                println!(
+                trace!(
                     " - collision in place: ({:>.3}, {:>.3}), at time of p1: {:>.3}, at time of p2: {:>.3}",
                     location.0,
                     location.1,
@@ -243,9 +251,9 @@ impl DaySolution {
 
         let (vx, vy) = vx_vy_candidates;
         //let debug = true || (vx == unit * -3) && (vy == unit);
-        let debug = false;
+        let debug = logging::enabled(Logging::Trace);
         if debug {
-            println!("find perfect match for (vx , vy) = ({vx}, {vy})");
+            trace!("find perfect match for (vx , vy) = ({vx}, {vy})");
         }
         let (hail1, hail2, rest_hails) = match &trajectories[..] {
             [h1, h2, tail @ ..] => (h1.clone(), h2.clone(), Vec::from(tail)),
@@ -273,7 +281,7 @@ impl DaySolution {
             let t2_den = vx * vy1 - vx * vy2 - vx1 * vy + vx1 * vy2 + vx2 * vy - vx2 * vy1;
 
             if debug {
-                println!("t1 denom = {t1_den}, t2 denom = {t2_den}");
+                trace!("t1 denom = {t1_den}, t2 denom = {t2_den}");
             }
 
             // If any of the denominators are zero, there is no solution
@@ -297,7 +305,7 @@ impl DaySolution {
                 } else {
                     let res = (t1, t2);
                     if debug {
-                        println!("compute_t1t2 = {res:?}");
+                        trace!("compute_t1t2 = {res:?}");
                     }
                     Some(res)
                 }
@@ -315,7 +323,7 @@ impl DaySolution {
             if result.is_integer() {
                 let res = result;
                 if debug {
-                    println!("compute_vz = {res}");
+                    trace!("compute_vz = {res}");
                 }
                 Some(res)
             } else {
@@ -334,7 +342,7 @@ impl DaySolution {
             if x0.is_integer() && y0.is_integer() && z0.is_integer() {
                 let res = Location(x0, y0, z0);
                 if debug {
-                    println!("compute_initial_pos = {res:?}");
+                    trace!("compute_initial_pos = {res:?}");
                 }
                 Some(res)
             } else {
@@ -362,8 +370,8 @@ impl DaySolution {
                             vel: Velocity(vx, vy, vz),
                         };
                         if debug {
-                            println!("proposed particle = {proposed_particle:?}");
-                            println!(
+                            trace!("proposed particle = {proposed_particle:?}");
+                            trace!(
                                 "test: p hits p1 = {}; p hits p2 = {}",
                                 proposed_particle.hits(&hail1),
                                 proposed_particle.hits(&hail2)
@@ -372,7 +380,7 @@ impl DaySolution {
                         if rest_hails.iter().all(|other| proposed_particle.hits(other)) {
                             let res = proposed_particle;
                             if debug {
-                                println!("_compute_perfect_shot = {res:?}");
+                                trace!("_compute_perfect_shot = {res:?}");
                             }
                             Some(res)
                         } else {
@@ -432,21 +440,22 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 24;
+    const TITLE: &'static str = "Never Tell Me The Odds";
 
-    type Answer = Option<i64>;
+    type Answer = Answer;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
-        text_input.lines().map(Particle::from).collect()
-    }
-
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
+        text_input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Particle::from(line).map_err(|e| e.offset_lines(i)))
+            .collect()
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
         let test = false;
-        let debug = false;
+        let debug = logging::enabled(Logging::Trace);
         let unit = R::from(1);
         let zero = unit * 0;
         let (p_lst, p_mst) = if test {
@@ -487,15 +496,19 @@ impl super::Solution for DaySolution {
                 let Location(x, y, _) = c.location;
 
                 if debug {
-                    println!(
+                    trace!(
                         "{} at time 1 {} & {} at time 2 {} are crossing the place ({}, {})",
-                        c.particle_1, c.time_1, c.particle_2, c.time_2, x, y
+                        c.particle_1,
+                        c.time_1,
+                        c.particle_2,
+                        c.time_2,
+                        x,
+                        y
                     );
                 }
             })
             .count();
-        let answer: i64 = answer as i64;
-        Some(answer)
+        Answer::from(answer)
     }
 
     fn solve_part_2(problem: Self::Problem) -> Self::Answer {
@@ -540,16 +553,10 @@ impl super::Solution for DaySolution {
             )
             .take(1)
             .nth(0)
-            .map(|x| x as i64)
-
-        // None
-    }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
+            .map_or_else(
+                || Answer::Failed(String::from("no velocity of the rock within the search limit")),
+                Answer::Number,
+            )
     }
 }
 
@@ -1,3 +1,6 @@
+use super::ParseError;
+use crate::logging::debug;
+use crate::Part;
 use regex::Regex;
 use std::collections::HashSet;
 
@@ -11,6 +14,7 @@ struct Edge {
     v1: Vertex,
     v2: Vertex,
 }
+#[derive(Clone)]
 pub struct Graph {
     vs: HashSet<Vertex>,
     es: HashSet<Edge>,
@@ -51,7 +55,7 @@ impl Graph {
 
 impl DaySolution {
     // this is parsing of input file data
-    fn extract_edges(line: &str) -> HashSet<Edge> {
+    fn extract_edges(line: &str) -> Result<HashSet<Edge>, ParseError> {
         let re_line = Regex::new(r#"(\w+): (.*)"#).unwrap();
         let re_v2s = Regex::new(r#"(\w+)"#).unwrap();
         let (v1, v2s_str) = re_line
@@ -60,27 +64,28 @@ impl DaySolution {
                 let (_, [v1, v2s]) = c.extract();
                 (v1, v2s)
             })
-            .unwrap();
+            .ok_or_else(|| ParseError::at(line, line, "expected '<name>: <names>'"))?;
         let v2s = re_v2s
             .captures_iter(v2s_str)
             .map(|c| c.get(0).unwrap().as_str());
 
-        v2s.map(|v2| {
-            let v1 = Vertex {
-                name: String::from(v1),
-            };
-            let v2 = Vertex {
-                name: String::from(v2),
-            };
-            /*
-            match v1.cmp(&v2) {
-                std::cmp::Ordering::Less => Edge { v1, v2 },
-                _ => Edge { v1: v2, v2: v1 },
-            }
-            */
-            Edge { v1, v2 }
-        })
-        .collect()
+        Ok(v2s
+            .map(|v2| {
+                let v1 = Vertex {
+                    name: String::from(v1),
+                };
+                let v2 = Vertex {
+                    name: String::from(v2),
+                };
+                /*
+                match v1.cmp(&v2) {
+                    std::cmp::Ordering::Less => Edge { v1, v2 },
+                    _ => Edge { v1: v2, v2: v1 },
+                }
+                */
+                Edge { v1, v2 }
+            })
+            .collect())
     }
     // this is parsing of input file data
     fn extract_vertices(edges: &HashSet<Edge>) -> HashSet<Vertex> {
@@ -93,21 +98,23 @@ impl DaySolution {
 
 impl super::Solution for DaySolution {
     const DAY_NUMBER: u8 = 25;
+    const TITLE: &'static str = "Snowverload";
+    const PARTS: &'static [Part] = &[Part::One];
 
     type Answer = Option<usize>;
     type Problem = P;
 
-    fn parse_input_part_1(text_input: String) -> Self::Problem {
+    fn parse_input_part_1(text_input: String) -> Result<Self::Problem, ParseError> {
         let es: HashSet<Edge> = text_input
             .lines()
-            .flat_map(DaySolution::extract_edges)
+            .enumerate()
+            .map(|(i, line)| DaySolution::extract_edges(line).map_err(|e| e.offset_lines(i)))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
             .collect();
         let vs = DaySolution::extract_vertices(&es);
-        Graph { vs, es }
-    }
-
-    fn parse_input_part_2(_text_input: String) -> Self::Problem {
-        Self::parse_input_part_1(_text_input)
+        Ok(Graph { vs, es })
     }
 
     fn solve_part_1(problem: Self::Problem) -> Self::Answer {
@@ -132,7 +139,7 @@ impl super::Solution for DaySolution {
         2 vertices that belong to different clusters are:
         ttc, txc
         */
-        println!("Initial number of connections: {}", es.len());
+        debug!("Initial number of connections: {}", es.len());
         let es_red: HashSet<Edge> = es
             .into_iter()
             .filter(
@@ -145,7 +152,7 @@ impl super::Solution for DaySolution {
             )
             .collect();
 
-        println!("Reduced number of connections: {}", es_red.len());
+        debug!("Reduced number of connections: {}", es_red.len());
 
         let g_red = Graph {
             vs: problem.vs,
@@ -159,7 +166,7 @@ impl super::Solution for DaySolution {
             name: String::from("txc"),
         });
 
-        println!(
+        debug!(
             "Total number of components: {}, 1st group: {}, 2nd group: {}",
             g_red.vs.len(),
             g1_count,
@@ -173,11 +180,4 @@ impl super::Solution for DaySolution {
     fn solve_part_2(_problem: Self::Problem) -> Self::Answer {
         None
     }
-
-    fn show_answer(answer: Self::Answer) -> String {
-        match answer {
-            Some(value) => format!("{}", value),
-            None => format!(""),
-        }
-    }
 }
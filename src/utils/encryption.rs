@@ -0,0 +1,258 @@
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/*
+The author of the puzzles asks not to publish the inputs, so they may be kept in the repository encrypted.
+Encrypted copy of `day_NN.txt` is `day_NN.txt.enc` next to it: header `AOC-ENC1`, random 24-byte nonce
+and XChaCha20-Poly1305 ciphertext of the input, authenticated together with the file name.
+Key is 32 bytes written as 64 hexadecimal digits in setting `input_key`
+(`AOC_INPUT_KEY` or `input_key = ...` in `aoc.conf`), `openssl rand -hex 32` makes a new one.
+When the plain input is absent, it is decrypted from the encrypted copy in memory, no plain file is written.
+*/
+pub const EXTENSION: &str = "enc";
+const HEADER: &[u8] = b"AOC-ENC1";
+const NONCE_LEN: usize = 24;
+
+#[derive(Debug)]
+pub enum CryptError {
+    /// Encrypted input was found, but setting `input_key` is not set
+    MissingKey,
+    BadKey,
+    /// File was encrypted with another key or is damaged
+    Undecryptable(PathBuf),
+    File(PathBuf, io::Error),
+}
+
+impl fmt::Display for CryptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CryptError::MissingKey => write!(
+                f,
+                "key of encrypted inputs is missing, set AOC_INPUT_KEY (see README)"
+            ),
+            CryptError::BadKey => write!(
+                f,
+                "key of encrypted inputs must be 64 hexadecimal digits (32 bytes)"
+            ),
+            CryptError::Undecryptable(path) => write!(
+                f,
+                "could not decrypt '{}': the key is different or the file is damaged",
+                path.display()
+            ),
+            CryptError::File(path, e) => write!(f, "could not access '{}': {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for CryptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CryptError::File(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Key the inputs are encrypted with
+pub struct InputKey(Key);
+
+impl InputKey {
+    pub fn parse(hex: &str) -> Result<InputKey, CryptError> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(CryptError::BadKey);
+        }
+        let bytes = (0..32)
+            .map(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| CryptError::BadKey)?;
+        Ok(InputKey(*Key::from_slice(&bytes)))
+    }
+
+    /// Key from setting `input_key`
+    pub fn load() -> Result<InputKey, CryptError> {
+        InputKey::parse(&super::setting("input_key").ok_or(CryptError::MissingKey)?)
+    }
+}
+
+/// Name the content is authenticated with, so that encrypted inputs of different days can't be swapped
+fn associated_name(path: &Path) -> Vec<u8> {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    name.trim_end_matches(&format!(".{EXTENSION}"))
+        .as_bytes()
+        .to_vec()
+}
+
+pub fn encrypt(key: &InputKey, name: &[u8], plain: &[u8]) -> Vec<u8> {
+    let cipher = XChaCha20Poly1305::new(&key.0);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let payload = Payload {
+        msg: plain,
+        aad: name,
+    };
+    let encrypted = cipher
+        .encrypt(&nonce, payload)
+        .expect("encryption of data in memory does not fail");
+    [HEADER, nonce.as_slice(), &encrypted].concat()
+}
+
+pub fn decrypt(key: &InputKey, name: &[u8], data: &[u8]) -> Option<Vec<u8>> {
+    let data = data.strip_prefix(HEADER)?;
+    if data.len() < NONCE_LEN {
+        return None;
+    }
+    let (nonce, encrypted) = data.split_at(NONCE_LEN);
+    let payload = Payload {
+        msg: encrypted,
+        aad: name,
+    };
+    XChaCha20Poly1305::new(&key.0)
+        .decrypt(XNonce::from_slice(nonce), payload)
+        .ok()
+}
+
+/// Path of the encrypted copy of the input file
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut encrypted = path.as_os_str().to_owned();
+    encrypted.push(format!(".{EXTENSION}"));
+    PathBuf::from(encrypted)
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, CryptError> {
+    fs::read(path).map_err(|e| CryptError::File(path.to_path_buf(), e))
+}
+
+fn write(path: &Path, content: &[u8]) -> Result<(), CryptError> {
+    fs::write(path, content).map_err(|e| CryptError::File(path.to_path_buf(), e))
+}
+
+fn decrypt_file(key: &InputKey, encrypted: &Path) -> Result<Vec<u8>, CryptError> {
+    decrypt(key, &associated_name(encrypted), &read(encrypted)?)
+        .ok_or_else(|| CryptError::Undecryptable(encrypted.to_path_buf()))
+}
+
+/// Content of the input file, decrypted from its encrypted copy when there is no plain file
+pub fn read_input(path: &Path) -> Result<Vec<u8>, CryptError> {
+    let encrypted = encrypted_path(path);
+    if path.exists() || !encrypted.exists() {
+        read(path)
+    } else {
+        decrypt_file(&InputKey::load()?, &encrypted)
+    }
+}
+
+/// Plain input files (`day_NN.txt`) or their encrypted copies in the input folder and in its year folders
+pub fn input_files(folder: &Path, encrypted: bool) -> Vec<PathBuf> {
+    let suffix = if encrypted { ".txt.enc" } else { ".txt" };
+    let entries = |folder: &Path| {
+        fs::read_dir(folder)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .collect::<Vec<_>>()
+    };
+    let mut files: Vec<PathBuf> = entries(folder)
+        .into_iter()
+        .flat_map(|path| match path.is_dir() {
+            true => entries(&path),
+            false => vec![path],
+        })
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("day_") && name.ends_with(suffix))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Write the encrypted copy of the input, unless the existing one already has the same content.
+/// Returns the path of the new copy.
+pub fn encrypt_input(key: &InputKey, path: &Path) -> Result<Option<PathBuf>, CryptError> {
+    let plain = read(path)?;
+    let encrypted = encrypted_path(path);
+    // every encryption gives a different file, keep the old one to keep the repository unchanged
+    if encrypted.exists() && decrypt_file(key, &encrypted).ok().as_ref() == Some(&plain) {
+        return Ok(None);
+    }
+    write(&encrypted, &encrypt(key, &associated_name(path), &plain))?;
+    Ok(Some(encrypted))
+}
+
+/// Write the plain input from its encrypted copy, unless the plain one exists.
+/// Returns the path of the new plain input.
+pub fn decrypt_input(key: &InputKey, encrypted: &Path) -> Result<Option<PathBuf>, CryptError> {
+    let path = encrypted.with_extension("");
+    if path.exists() {
+        return Ok(None);
+    }
+    write(&path, &decrypt_file(key, encrypted)?)?;
+    Ok(Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn decrypt() {
+        let key = InputKey::parse(KEY).unwrap();
+        let encrypted = encrypt(&key, b"day_01.txt", b"1abc2\n");
+        assert!(encrypted.starts_with(HEADER));
+        assert_ne!(encrypted, encrypt(&key, b"day_01.txt", b"1abc2\n"));
+        assert_eq!(
+            super::decrypt(&key, b"day_01.txt", &encrypted),
+            Some(b"1abc2\n".to_vec())
+        );
+        assert_eq!(super::decrypt(&key, b"day_02.txt", &encrypted), None);
+        let other = InputKey::parse(&KEY.replace('0', "f")).unwrap();
+        assert_eq!(super::decrypt(&other, b"day_01.txt", &encrypted), None);
+        assert!(matches!(InputKey::parse("0102"), Err(CryptError::BadKey)));
+        assert!(matches!(
+            InputKey::parse(&KEY.replace('0', "g")),
+            Err(CryptError::BadKey)
+        ));
+    }
+
+    #[test]
+    fn encrypt_input() {
+        let folder = std::env::temp_dir().join(format!("aoc_encryption_{}", std::process::id()));
+        let key = InputKey::parse(KEY).unwrap();
+        let file = folder.join("2022/day_01.txt");
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        fs::write(&file, "1000\n").unwrap();
+        fs::write(folder.join("day_02.txt"), "A Y\n").unwrap();
+        fs::write(folder.join("MANIFEST"), "").unwrap();
+        assert_eq!(
+            input_files(&folder, false),
+            [folder.join("2022/day_01.txt"), folder.join("day_02.txt")]
+        );
+
+        let encrypted = folder.join("2022/day_01.txt.enc");
+        assert_eq!(
+            super::encrypt_input(&key, &file).unwrap(),
+            Some(encrypted.clone())
+        );
+        let content = fs::read(&encrypted).unwrap();
+        assert_eq!(super::encrypt_input(&key, &file).unwrap(), None);
+        assert_eq!(fs::read(&encrypted).unwrap(), content);
+        assert_eq!(input_files(&folder, true), std::slice::from_ref(&encrypted));
+
+        assert_eq!(super::decrypt_input(&key, &encrypted).unwrap(), None);
+        fs::remove_file(&file).unwrap();
+        assert_eq!(
+            super::decrypt_input(&key, &encrypted).unwrap(),
+            Some(file.clone())
+        );
+        assert_eq!(fs::read_to_string(&file).unwrap(), "1000\n");
+        fs::remove_dir_all(folder).unwrap();
+    }
+}
@@ -0,0 +1,312 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::{Site, SiteError};
+use crate::{year_folder, Day, Part, Year, ALL_DAYS};
+
+/*
+Private leaderboard is served as JSON by `/{year}/leaderboard/private/view/{id}.json` to the members of it.
+The site asks not to request it more often than once in 15 minutes,
+so the response is saved to `./leaderboards/YYYY/{id}.json` and reused while it is fresh.
+*/
+pub const MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// Member of the leaderboard with the times their stars were got
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// Anonymous members have no name
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    pub star_times: BTreeMap<Day, [Option<DateTime<Utc>>; 2]>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn star_time(&self, day: Day, part: Part) -> Option<DateTime<Utc>> {
+        self.star_times.get(&day)?[part.number() as usize - 1]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: Year,
+    /// Members ordered by local score, the best first
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(year: Year, json: &str) -> Option<Leaderboard> {
+        let json: Value = serde_json::from_str(json).ok()?;
+        let mut members = json["members"]
+            .as_object()?
+            .values()
+            .map(parse_member)
+            .collect::<Option<Vec<_>>>()?;
+        members.sort_by(|a, b| {
+            (b.local_score, b.stars)
+                .cmp(&(a.local_score, a.stars))
+                .then(a.id.cmp(&b.id))
+        });
+        Some(Leaderboard { year, members })
+    }
+}
+
+fn parse_member(json: &Value) -> Option<Member> {
+    let time = |star: &Value| DateTime::from_timestamp(star["get_star_ts"].as_i64()?, 0);
+    let star_times = json["completion_day_level"]
+        .as_object()?
+        .iter()
+        .filter_map(|(day, parts)| {
+            Some((day.parse().ok()?, [time(&parts["1"]), time(&parts["2"])]))
+        })
+        .collect();
+    Some(Member {
+        id: json["id"].as_u64()?,
+        name: json["name"].as_str().map(String::from),
+        local_score: json["local_score"].as_u64().unwrap_or(0),
+        stars: json["stars"].as_u64().unwrap_or(0),
+        star_times,
+    })
+}
+
+fn cache_path(root: &Path, year: Year, id: u64) -> PathBuf {
+    year_folder(&root.join("leaderboards"), year).join(format!("{id}.json"))
+}
+
+pub fn load(year: Year, id: u64, refresh: bool) -> Result<Leaderboard, SiteError> {
+    let max_age = if refresh { Duration::ZERO } else { MAX_AGE };
+    load_into(Path::new("."), &Site::load(), year, id, max_age)
+}
+
+/// Leaderboard saved under `root` if it is younger than `max_age`, otherwise the one fetched from the site
+pub fn load_into(
+    root: &Path,
+    site: &Site,
+    year: Year,
+    id: u64,
+    max_age: Duration,
+) -> Result<Leaderboard, SiteError> {
+    let cached = cache_path(root, year, id);
+    let fresh = fs::metadata(&cached)
+        .and_then(|meta| meta.modified())
+        .is_ok_and(|time| time.elapsed().is_ok_and(|age| age < max_age));
+    let json = match fresh.then(|| fs::read_to_string(&cached).ok()).flatten() {
+        Some(json) => json,
+        None => {
+            site.session()?;
+            let path = format!("/{year}/leaderboard/private/view/{id}.json");
+            let json = site.fetch(&path)?;
+            Leaderboard::parse(year, &json)
+                .ok_or_else(|| SiteError::UnexpectedPage(site.url(&path)))?;
+            super::write_file(&cached, json.as_bytes())?;
+            json
+        }
+    };
+    Leaderboard::parse(year, &json).ok_or(SiteError::UnexpectedPage(cached.display().to_string()))
+}
+
+/// Time from unlock of the day to the star, e.g. `01:02:03` or `2d 01:02:03`
+fn since_unlock(year: Year, day: Day, time: Option<DateTime<Utc>>) -> String {
+    let Some(time) = time else {
+        return String::from("-");
+    };
+    let seconds = (time - super::unlock_time(year, day)).num_seconds().max(0);
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    match days {
+        0 => clock,
+        days => format!("{days}d {clock}"),
+    }
+}
+
+/// Table of members with their scores and stars of every day:
+/// `*` for both stars of the day, `+` for the first one only, `.` for none
+pub fn render(leaderboard: &Leaderboard) -> String {
+    let width = name_width(leaderboard);
+    let tens: String = ALL_DAYS
+        .map(|day| match day / 10 {
+            0 => ' ',
+            tens => char::from(b'0' + tens),
+        })
+        .collect();
+    let ones: String = ALL_DAYS.map(|day| char::from(b'0' + day % 10)).collect();
+    let mut lines = vec![
+        format!("{:>3}  {:<width$}  {:>5}  {:>5}  {tens}", "", "", "", ""),
+        format!(
+            "{:>3}  {:<width$}  {:>5}  {:>5}  {ones}",
+            "#", "Member", "Score", "Stars"
+        ),
+    ];
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        let stars: String = ALL_DAYS
+            .map(|day| {
+                match (
+                    member.star_time(day, Part::One),
+                    member.star_time(day, Part::Two),
+                ) {
+                    (Some(_), Some(_)) => '*',
+                    (Some(_), None) => '+',
+                    _ => '.',
+                }
+            })
+            .collect();
+        lines.push(format!(
+            "{:>3}  {:<width$}  {:>5}  {:>5}  {stars}",
+            rank + 1,
+            member.display_name(),
+            member.local_score,
+            member.stars
+        ));
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Times of both stars of the day since its unlock, for every member who has got a star.
+/// `local` are the times of the correct answers submitted from here, shown as the first row when present.
+pub fn render_day(
+    leaderboard: &Leaderboard,
+    day: Day,
+    local: [Option<DateTime<Utc>>; 2],
+) -> String {
+    let year = leaderboard.year;
+    let width = name_width(leaderboard).max("(local submissions)".len());
+    let mut members: Vec<&Member> = leaderboard
+        .members
+        .iter()
+        .filter(|m| m.star_time(day, Part::One).is_some())
+        .collect();
+    // members who have both stars go first, ordered by the time of the second one
+    members.sort_by_key(|m| {
+        let two = m.star_time(day, Part::Two);
+        (two.is_none(), two, m.star_time(day, Part::One))
+    });
+    let mut lines = vec![format!(
+        "{:>3}  {:<width$}  {:>12}  {:>12}",
+        "#", "Member", "Part 1", "Part 2"
+    )];
+    let row = |rank: String, name: String, [one, two]: [Option<DateTime<Utc>>; 2]| {
+        format!(
+            "{rank:>3}  {name:<width$}  {:>12}  {:>12}",
+            since_unlock(year, day, one),
+            since_unlock(year, day, two)
+        )
+    };
+    if local.iter().any(Option::is_some) {
+        lines.push(row(
+            String::new(),
+            String::from("(local submissions)"),
+            local,
+        ));
+    }
+    for (rank, member) in members.into_iter().enumerate() {
+        lines.push(row(
+            (rank + 1).to_string(),
+            member.display_name(),
+            [
+                member.star_time(day, Part::One),
+                member.star_time(day, Part::Two),
+            ],
+        ));
+    }
+    lines.join("\n") + "\n"
+}
+
+fn name_width(leaderboard: &Leaderboard) -> usize {
+    leaderboard
+        .members
+        .iter()
+        .map(|m| m.display_name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Member".len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::stand_in;
+
+    const JSON: &str = include_str!("test_pages/leaderboard.json");
+
+    #[test]
+    fn parse() {
+        let leaderboard = Leaderboard::parse(2023, JSON).unwrap();
+        let names: Vec<String> = leaderboard
+            .members
+            .iter()
+            .map(Member::display_name)
+            .collect();
+        assert_eq!(names, ["Alice", "(anonymous user #1002)", "Carol"]);
+        let alice = &leaderboard.members[0];
+        assert_eq!(
+            alice.star_time(1, Part::Two),
+            DateTime::from_timestamp(1701411300, 0)
+        );
+        assert_eq!(alice.star_time(2, Part::Two), None);
+        assert_eq!(Leaderboard::parse(2023, "<html></html>"), None);
+    }
+
+    #[test]
+    fn render() {
+        let leaderboard = Leaderboard::parse(2023, JSON).unwrap();
+        let table = super::render(&leaderboard);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].ends_with("          1111111111222222"));
+        assert!(lines[1].ends_with("  1234567890123456789012345"));
+        assert_eq!(
+            lines[2],
+            "  1  Alice                       9      3  *+......................."
+        );
+
+        let local = [DateTime::from_timestamp(1701406900, 0), None];
+        assert_eq!(
+            render_day(&leaderboard, 1, local),
+            "  #  Member                        Part 1        Part 2\n     \
+            (local submissions)         00:01:40             -\n  \
+            1  (anonymous user #1002)      00:20:00      00:36:40\n  \
+            2  Alice                       00:45:00      01:15:00\n"
+        );
+        assert_eq!(
+            since_unlock(2023, 2, DateTime::from_timestamp(1701500000 + 2 * 86400, 0)),
+            "2d 01:53:20"
+        );
+    }
+
+    #[test]
+    fn load_into() {
+        let root = std::env::temp_dir().join(format!("aoc_leaderboard_{}", std::process::id()));
+        let server = stand_in::serve(vec![(200, String::from(JSON))]);
+        let site = Site {
+            base_url: server.url.clone(),
+            session: Some(String::from("abc")),
+            ..Site::default()
+        };
+        let first = super::load_into(&root, &site, 2023, 1001, MAX_AGE).unwrap();
+        // the second load is served from the saved copy, the stand-in has no more responses
+        let second = super::load_into(&root, &site, 2023, 1001, MAX_AGE).unwrap();
+        assert_eq!(first, second);
+        assert!(root.join("leaderboards/1001.json").is_file());
+
+        let request = server.requests().remove(0);
+        assert!(request.starts_with("GET /2023/leaderboard/private/view/1001.json HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc"));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/*
+Checksums of downloaded inputs are kept in `./input/MANIFEST`, one line per file
+in the format of `md5sum`: MD5 of the file, two spaces and the path relative to the input folder,
+so `cd input && md5sum -c MANIFEST` checks the inputs as well.
+An input is recorded when it is downloaded, the line of a file downloaded again is replaced.
+*/
+pub const MANIFEST: &str = "MANIFEST";
+
+/// State of an input file compared with the manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Intact,
+    /// File is not in the manifest, e.g. it was downloaded before the manifest was kept
+    Unrecorded,
+    Missing,
+    /// Content is intact, but lines end with CRLF instead of LF
+    CrlfLineEnds,
+    /// Content differs and does not end with a new line, as every downloaded input does
+    Truncated,
+    Edited,
+}
+
+impl Status {
+    /// The input may not give the right answer
+    pub fn is_damaged(&self) -> bool {
+        matches!(
+            self,
+            Status::Missing | Status::CrlfLineEnds | Status::Truncated | Status::Edited
+        )
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Intact => write!(f, "intact"),
+            Status::Unrecorded => write!(f, "not recorded in the manifest"),
+            Status::Missing => write!(f, "missing"),
+            Status::CrlfLineEnds => write!(f, "lines end with CRLF, convert them to LF"),
+            Status::Truncated => write!(f, "truncated, download it again"),
+            Status::Edited => write!(f, "changed since download, download it again"),
+        }
+    }
+}
+
+pub fn checksum(content: &[u8]) -> String {
+    format!("{:x}", md5::compute(content))
+}
+
+/// Path of the file inside of the input folder, as it is written in the manifest
+fn entry_name(folder: &Path, file: &Path) -> String {
+    file.strip_prefix(folder)
+        .unwrap_or(file)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Checksum of the file recorded in the manifest of the input folder
+fn recorded(folder: &Path, file: &Path) -> Option<String> {
+    let manifest = fs::read_to_string(folder.join(MANIFEST)).ok()?;
+    let name = entry_name(folder, file);
+    manifest
+        .lines()
+        .filter_map(|line| line.split_once("  "))
+        .rfind(|(_, path)| *path == name)
+        .map(|(sum, _)| String::from(sum))
+}
+
+/// Save checksum of the content of `file` to the manifest of the input folder
+pub fn record(folder: &Path, file: &Path, content: &[u8]) -> io::Result<()> {
+    let path = folder.join(MANIFEST);
+    let name = entry_name(folder, file);
+    let manifest = fs::read_to_string(&path).unwrap_or_default();
+    let mut lines: Vec<&str> = manifest
+        .lines()
+        .filter(|line| line.split_once("  ").is_none_or(|(_, p)| p != name))
+        .collect();
+    let line = format!("{}  {name}", checksum(content));
+    lines.push(&line);
+    lines.sort_by_key(|line| line.split_once("  ").map(|(_, p)| p));
+    fs::write(path, lines.join("\n") + "\n")
+}
+
+/// Compare the file in the input folder with its checksum in the manifest,
+/// a file kept encrypted is compared after decryption
+pub fn verify(folder: &Path, file: &Path) -> Status {
+    let content = super::encryption::read_input(file).ok();
+    match (recorded(folder, file), content) {
+        (_, None) => Status::Missing,
+        (None, Some(_)) => Status::Unrecorded,
+        (Some(sum), Some(content)) if sum == checksum(&content) => Status::Intact,
+        (Some(sum), Some(content)) => {
+            let text = String::from_utf8_lossy(&content);
+            if text.contains("\r\n") && sum == checksum(text.replace("\r\n", "\n").as_bytes()) {
+                Status::CrlfLineEnds
+            } else if !content.ends_with(b"\n") {
+                Status::Truncated
+            } else {
+                Status::Edited
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify() {
+        let folder = std::env::temp_dir().join(format!("aoc_manifest_{}", std::process::id()));
+        let file = folder.join("2022/day_01.txt");
+        let input = "1000\n2000\n\n3000\n";
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        assert_eq!(super::verify(&folder, &file), Status::Missing);
+        fs::write(&file, input).unwrap();
+        assert_eq!(super::verify(&folder, &file), Status::Unrecorded);
+
+        record(&folder, &folder.join("day_02.txt"), b"A Y\n").unwrap();
+        record(&folder, &file, b"old\n").unwrap();
+        record(&folder, &file, input.as_bytes()).unwrap();
+        assert_eq!(
+            fs::read_to_string(folder.join(MANIFEST)).unwrap(),
+            format!(
+                "{}  2022/day_01.txt\n{}  day_02.txt\n",
+                checksum(input.as_bytes()),
+                checksum(b"A Y\n")
+            )
+        );
+        assert_eq!(super::verify(&folder, &file), Status::Intact);
+
+        let check = |content: &str| {
+            fs::write(&file, content).unwrap();
+            super::verify(&folder, &file)
+        };
+        assert_eq!(check(&input.replace('\n', "\r\n")), Status::CrlfLineEnds);
+        assert_eq!(check("1000\n2000\n\n30"), Status::Truncated);
+        assert_eq!(check("1000\n2000\n\n3001\n"), Status::Edited);
+        fs::remove_dir_all(folder).unwrap();
+    }
+}
@@ -1,52 +1,276 @@
-use chrono::{TimeZone, Utc};
-use curl::easy::Easy;
-use std::fs::{self, File};
-use std::io::{stdout, Write};
-use std::path::Path;
+use chrono::{DateTime, TimeZone, Utc};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
 
-pub fn download_input(year: i32, day: u32) -> Result<(), String> {
+use crate::{year_folder, Day, Year};
+
+pub mod encryption;
+pub mod leaderboard;
+pub mod manifest;
+pub mod puzzle;
+mod site;
+#[cfg(test)]
+mod stand_in;
+pub mod submit;
+
+pub use site::{setting, Site, BASE_URL, CONFIG_FILE, SESSION_FILE};
+
+/// Reason why the exchange with the site failed
+#[derive(Debug)]
+pub enum SiteError {
+    /// There are no puzzles in the year
+    NoPuzzles(Year),
+    /// The day unlocks at the given time
+    Locked(DateTime<Utc>),
+    /// Session cookie is needed but `secrets/SESSION` is missing
+    MissingSession,
+    /// The site did not accept the session cookie
+    SessionRejected,
+    NotFound(String),
+    Status(String, u32),
+    /// Response is not what was expected from the page
+    UnexpectedPage(String),
+    Network(curl::Error),
+    File(PathBuf, io::Error),
+}
+
+impl fmt::Display for SiteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SiteError::NoPuzzles(year) => {
+                write!(
+                    f,
+                    "there are no puzzles in {year}, Advent of Code starts from 2015"
+                )
+            }
+            SiteError::Locked(unlock) => write!(
+                f,
+                "puzzle is not unlocked yet, it will be available at {}",
+                unlock.format("%Y-%m-%d %H:%M UTC")
+            ),
+            SiteError::MissingSession => write!(
+                f,
+                "session cookie is missing, save it to '{SESSION_FILE}' or set AOC_SESSION (see README)"
+            ),
+            SiteError::SessionRejected => write!(
+                f,
+                "the site did not accept the session cookie, it has probably expired: \
+                log in again and update the session"
+            ),
+            SiteError::NotFound(url) => {
+                write!(f, "{url} was not found, the puzzle may not be unlocked yet")
+            }
+            SiteError::Status(url, status) => write!(f, "{url} responded with status {status}"),
+            SiteError::UnexpectedPage(url) => write!(f, "response of {url} is not recognized"),
+            SiteError::Network(e) => write!(f, "could not reach the site: {e}"),
+            SiteError::File(path, e) => write!(f, "could not write '{}': {e}", path.display()),
+        }
+    }
+}
+
+impl SiteError {
+    /// The request may succeed when it is repeated a bit later
+    pub fn is_transient(&self) -> bool {
+        match self {
+            // input of a day that has just been unlocked may not be served yet
+            SiteError::NotFound(_) | SiteError::Network(_) => true,
+            SiteError::Status(_, status) => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+impl std::error::Error for SiteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SiteError::Network(e) => Some(e),
+            SiteError::File(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<curl::Error> for SiteError {
+    fn from(e: curl::Error) -> Self {
+        SiteError::Network(e)
+    }
+}
+
+/// Write file with all parent folders
+fn write_file(path: &Path, content: &[u8]) -> Result<(), SiteError> {
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, content))
+        .map_err(|e| SiteError::File(path.to_path_buf(), e))
+}
+
+/// Time when the puzzle of the day is published: midnight of US Eastern time
+pub fn unlock_time(year: Year, day: Day) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year as i32, 12, day as u32, 5, 0, 0)
+        .unwrap()
+}
+
+/// Run the request until it succeeds or fails permanently, at most `attempts` times.
+/// The delay between attempts starts with `delay` and doubles after each one,
+/// `on_retry` is told about the failure and the delay before the next attempt.
+pub fn retry<T>(
+    attempts: u32,
+    delay: Duration,
+    mut request: impl FnMut() -> Result<T, SiteError>,
+    mut on_retry: impl FnMut(&SiteError, Duration),
+) -> Result<T, SiteError> {
+    let mut delay = delay;
+    for _ in 1..attempts {
+        match request() {
+            Err(e) if e.is_transient() => {
+                on_retry(&e, delay);
+                thread::sleep(delay);
+                delay *= 2;
+            }
+            result => return result,
+        }
+    }
+    request()
+}
+
+/// Result of successful download of the input
+#[derive(Debug, PartialEq, Eq)]
+pub enum Download {
+    /// Input has been downloaded earlier, the file is kept as is
+    Present(PathBuf),
+    Saved(PathBuf),
+}
+
+pub fn download_input(year: Year, day: Day) -> Result<Download, SiteError> {
     // implementation of https://www.reddit.com/r/adventofcode/comments/a2vonl/how_to_download_inputs_with_a_script/
-    let requested_date = Utc.with_ymd_and_hms(year, 12, day, 5, 0, 0).unwrap();
-    let this_date = Utc::now();
-    let file = format!("./input/day_{:0>2}.txt", day);
     if year < 2015 {
-        Err(format!("Year is too low! Advent of Code starts from 2015. Year you provided is {year}. Change year and try again"))
-    } else if requested_date > this_date {
-        Err(format!(
-            "Date is too high! Requested date has not come yet. Date you requested is {}",
-            requested_date.format("%Y-%b-%d")
-        ))
-    } else if Path::new(&file).exists() {
-        println!("File has already been downloaded earlier. Content:");
-        stdout().write_all(&fs::read(&file).unwrap()).unwrap();
-        // add one empty line
-        stdout().write(b"\n").unwrap();
-        Ok(())
-    } else {
-        let mut f = File::create(&file).unwrap();
-        //let mut f = File::open(&file).unwrap();
-
-        let key = fs::read_to_string("./secrets/SESSION")
-            .expect("Secret file 'SESSION' with cookie session id is missing");
-        println!("download file using cookie session={key}");
-        let url = format!("https://adventofcode.com/{year}/day/{day}/input");
-        let mut handle = Easy::new();
-        handle.url(&url).unwrap();
-        handle.cookie(format!("session={key}").as_str()).unwrap();
-        handle
-            .write_function(move |data| {
-                f.write_all(data).unwrap();
-                //std::fs::write(&file, data).unwrap();
-                Ok(data.len())
-            })
-            .unwrap();
-
-        handle
-            .perform()
-            .map(|_| {
-                println!("File has been downloaded. Content:");
-                stdout().write_all(&fs::read(&file).unwrap()).unwrap();
-            })
-            .map_err(|e| String::from(e.description()))
+        return Err(SiteError::NoPuzzles(year));
+    }
+    let unlock = unlock_time(year, day);
+    if unlock > Utc::now() {
+        return Err(SiteError::Locked(unlock));
+    }
+    let folder = Path::new("./input");
+    let file = input_file(folder, year, day);
+    if file.exists() {
+        return Ok(Download::Present(file));
+    }
+    download_input_to(folder, &Site::load(), year, day).map(Download::Saved)
+}
+
+fn input_file(folder: &Path, year: Year, day: Day) -> PathBuf {
+    year_folder(folder, year).join(format!("day_{:0>2}.txt", day))
+}
+
+/// Download input from the site into the input `folder` and record its checksum in the manifest.
+/// Input is written to a temporary file first, which replaces the input file only when the whole input is received,
+/// so that failed request never leaves empty or partial input behind.
+pub fn download_input_to(
+    folder: &Path,
+    site: &Site,
+    year: Year,
+    day: Day,
+) -> Result<PathBuf, SiteError> {
+    site.session()?;
+    let input = site.fetch(&format!("/{year}/day/{day}/input"))?;
+    let file = input_file(folder, year, day);
+    let mut temporary = file.as_os_str().to_owned();
+    temporary.push(".part");
+    let temporary = PathBuf::from(temporary);
+    write_file(&temporary, input.as_bytes())?;
+    fs::rename(&temporary, &file).map_err(|e| {
+        let _ = fs::remove_file(&temporary);
+        SiteError::File(file.clone(), e)
+    })?;
+    manifest::record(folder, &file, input.as_bytes())
+        .map_err(|e| SiteError::File(folder.join(manifest::MANIFEST), e))?;
+    Ok(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn download_input_to() {
+        let root = std::env::temp_dir().join(format!("aoc_input_{}", std::process::id()));
+        let folder = root.join("input");
+        let file = folder.join("2022/day_01.txt");
+        let server = stand_in::serve(vec![
+            (
+                400,
+                String::from("Puzzle inputs differ by user.  Please log in."),
+            ),
+            (
+                404,
+                String::from("Please don't repeatedly request this endpoint."),
+            ),
+            (200, String::from("1abc2\n")),
+        ]);
+        let download = |session: &str| {
+            let site = Site {
+                base_url: server.url.clone(),
+                session: Some(String::from(session)),
+                ..Site::default()
+            };
+            super::download_input_to(&folder, &site, 2022, 1)
+        };
+        assert!(matches!(
+            super::download_input_to(&folder, &Site::default(), 2022, 1),
+            Err(SiteError::MissingSession)
+        ));
+        assert!(matches!(download("old"), Err(SiteError::SessionRejected)));
+        assert!(!file.exists());
+        assert!(matches!(download("abc"), Err(SiteError::NotFound(_))));
+        assert!(!file.exists());
+        assert_eq!(download("abc").unwrap(), file);
+        assert_eq!(fs::read_to_string(&file).unwrap(), "1abc2\n");
+        assert_eq!(fs::read_dir(file.parent().unwrap()).unwrap().count(), 1);
+        assert_eq!(manifest::verify(&folder, &file), manifest::Status::Intact);
+
+        let requests = server.requests();
+        assert!(requests[2].starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(requests[2].contains("Cookie: session=abc"));
+        assert!(requests[2].contains("User-Agent: aoc2023_rust/"));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn retry() {
+        let server = stand_in::serve(vec![
+            (503, String::from("Service Unavailable")),
+            (404, String::from("Not Found")),
+            (200, String::from("1abc2\n")),
+            (400, String::from("Bad Request")),
+        ]);
+        let site = Site {
+            base_url: server.url.clone(),
+            session: Some(String::from("abc")),
+            ..Site::default()
+        };
+        let mut retries = vec![];
+        let fetch = || site.fetch("/2023/day/1/input");
+        let result = super::retry(5, Duration::from_millis(1), fetch, |e, delay| {
+            retries.push((e.to_string(), delay))
+        });
+        assert_eq!(result.unwrap(), "1abc2\n");
+        assert_eq!(retries.len(), 2);
+        assert_eq!(retries[1].1, Duration::from_millis(2));
+        // a rejected session is not retried
+        let result = super::retry(5, Duration::from_millis(1), fetch, |_, _| panic!());
+        assert!(matches!(result, Err(SiteError::SessionRejected)));
+        assert_eq!(server.requests().len(), 4);
+    }
+
+    #[test]
+    fn unlock_time() {
+        assert_eq!(
+            super::unlock_time(2023, 1).to_rfc3339(),
+            "2023-12-01T05:00:00+00:00"
+        );
     }
 }
@@ -0,0 +1,341 @@
+use std::path::{Path, PathBuf};
+
+use super::{Site, SiteError};
+use crate::{year_folder, Day, Year};
+
+/*
+Puzzle page `/{year}/day/{day}` keeps the description of each part in its own `<article class="day-desc">`,
+the second one is present only for a session that has solved part 1.
+Description is saved as Markdown to `./puzzles/YYYY/day_NN.md`, it is refreshed on every download.
+Every `<pre><code>` block of the description is taken as an example input:
+the first one becomes the default example `./input_test/YYYY/day_NN.txt`,
+the following ones are saved as named examples `./input_test/YYYY/day_NN/example_K.txt`.
+Examples that already exist are kept as they are, they may have been corrected by hand.
+*/
+
+/// Files written by the download of the puzzle page
+#[derive(Debug, PartialEq, Eq)]
+pub struct Downloaded {
+    pub description: PathBuf,
+    pub examples: Vec<PathBuf>,
+}
+
+pub fn download_puzzle(year: Year, day: Day) -> Result<Downloaded, SiteError> {
+    download_puzzle_into(Path::new("."), &Site::load(), year, day)
+}
+
+/// Download puzzle page from the site and save its description and examples under `root`
+pub fn download_puzzle_into(
+    root: &Path,
+    site: &Site,
+    year: Year,
+    day: Day,
+) -> Result<Downloaded, SiteError> {
+    let path = format!("/{year}/day/{day}");
+    let html = site.fetch(&path)?;
+    let articles = articles(&html);
+    if articles.is_empty() {
+        return Err(SiteError::UnexpectedPage(site.url(&path)));
+    }
+    let file_name = format!("day_{:0>2}", day);
+    let write = |path: &Path, text: &str| super::write_file(path, text.as_bytes());
+
+    let markdown: Vec<String> = articles.iter().map(|a| to_markdown(a)).collect();
+    let description = year_folder(&root.join("puzzles"), year).join(format!("{file_name}.md"));
+    write(&description, &markdown.join("\n"))?;
+
+    let folder = year_folder(&root.join("input_test"), year);
+    let mut examples = vec![];
+    for (idx, example) in articles.iter().flat_map(|a| examples_of(a)).enumerate() {
+        let path = match idx {
+            0 => folder.join(format!("{file_name}.txt")),
+            _ => folder
+                .join(&file_name)
+                .join(format!("example_{}.txt", idx + 1)),
+        };
+        if !path.exists() {
+            write(&path, &example)?;
+            examples.push(path);
+        }
+    }
+    Ok(Downloaded {
+        description,
+        examples,
+    })
+}
+
+/// Inner HTML of every `<article>` element
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let body = &rest[start + open_end + 1..];
+        let end = body.find("</article>").unwrap_or(body.len());
+        articles.push(&body[..end]);
+        rest = &body[end..];
+    }
+    articles
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open(&'a str, &'a str),
+    Close(&'a str),
+}
+
+/// Split HTML into text and tags, attributes of tags are kept unparsed
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                // an unclosed tag ends the markup, keep the rest as text
+                let Some(end) = rest.find('>') else {
+                    tokens.push(Token::Text(rest));
+                    break;
+                };
+                let tag = rest[1..end].trim_end_matches('/').trim();
+                let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+                tokens.push(match name.strip_prefix('/') {
+                    Some(name) => Token::Close(name),
+                    None => Token::Open(name, attributes),
+                });
+                rest = &rest[end + 1..];
+            }
+            Some(start) => {
+                tokens.push(Token::Text(&rest[..start]));
+                rest = &rest[start..];
+            }
+            None => {
+                tokens.push(Token::Text(rest));
+                rest = "";
+            }
+        }
+    }
+    tokens
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{name}=\""))? + name.len() + 2;
+    let len = attributes[start..].find('"')?;
+    Some(&attributes[start..start + len])
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let character = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => name
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| name.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        });
+        match (character, entity) {
+            (Some(c), Some((_, end))) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Convert description of a puzzle part to Markdown
+fn to_markdown(article: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut links: Vec<String> = vec![];
+    for token in tokens(article) {
+        match token {
+            Token::Text(text) if in_pre => out.push_str(&decode_entities(text)),
+            Token::Text(text) => {
+                let text = decode_entities(text).replace('\n', " ");
+                if out.is_empty() || out.ends_with('\n') {
+                    out.push_str(text.trim_start());
+                } else {
+                    out.push_str(&text);
+                }
+            }
+            Token::Open("pre", _) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            Token::Close("pre") => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            _ if in_pre => (),
+            Token::Open("h2", _) => out.push_str("## "),
+            Token::Close("h2" | "p") => out.push_str("\n\n"),
+            // inline code is shown as is, without emphasis
+            Token::Open("em", _) | Token::Close("em") if in_code => (),
+            Token::Open("em", _) | Token::Close("em") => out.push('*'),
+            Token::Open("code", _) => {
+                in_code = true;
+                out.push('`');
+            }
+            Token::Close("code") => {
+                in_code = false;
+                out.push('`');
+            }
+            Token::Open("li", _) => out.push_str("- "),
+            Token::Close("li") => out.push('\n'),
+            Token::Close("ul") => out.push('\n'),
+            Token::Open("a", attributes) => {
+                links.push(String::from(attribute(attributes, "href").unwrap_or("")));
+                out.push('[');
+            }
+            Token::Close("a") => {
+                out.push_str(&format!("]({})", links.pop().unwrap_or_default()));
+            }
+            _ => (),
+        }
+    }
+    while out.contains("\n\n\n") {
+        out = out.replace("\n\n\n", "\n\n");
+    }
+    format!("{}\n", out.trim())
+}
+
+/// Text of every `<pre><code>` block of the description
+fn examples_of(article: &str) -> Vec<String> {
+    let mut examples = vec![];
+    let mut example: Option<String> = None;
+    for token in tokens(article) {
+        match (token, &mut example) {
+            (Token::Open("pre", _), None) => example = Some(String::new()),
+            (Token::Text(text), Some(example)) => example.push_str(&decode_entities(text)),
+            (Token::Close("pre"), Some(text)) => {
+                if !text.ends_with('\n') {
+                    text.push('\n');
+                }
+                examples.push(text.clone());
+                example = None;
+            }
+            _ => (),
+        }
+    }
+    examples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::stand_in;
+    use std::fs;
+
+    const PAGE: &str = include_str!("test_pages/puzzle.html");
+
+    fn site(url: &str, session: Option<&str>) -> Site {
+        Site {
+            base_url: String::from(url),
+            session: session.map(String::from),
+            ..Site::default()
+        }
+    }
+
+    #[test]
+    fn decode_entities() {
+        assert_eq!(
+            super::decode_entities("a &lt;b&gt; &amp;&#39;&#x41;"),
+            "a <b> &'A"
+        );
+        assert_eq!(super::decode_entities("x & y &unknown;"), "x & y &unknown;");
+    }
+
+    #[test]
+    fn unclosed_tag() {
+        for html in ["a <", "a <é", "a <p"] {
+            let tokens = tokens(html);
+            assert!(
+                matches!(tokens[..], [Token::Text("a "), Token::Text(rest)] if rest == &html[2..])
+            );
+        }
+    }
+
+    #[test]
+    fn to_markdown() {
+        let articles = articles(PAGE);
+        assert_eq!(articles.len(), 2);
+        assert_eq!(
+            super::to_markdown(articles[0]),
+            "## --- Day 1: Example Puzzle ---\n\n\
+            Each line has a *number* in it, see [the list](/2023/about).\n\n\
+            ```\n1 and 2\n3 < 4\n```\n\n\
+            - first `item`\n- second item\n\n\
+            The answer is `10`.\n"
+        );
+    }
+
+    #[test]
+    fn examples_of() {
+        let articles = articles(PAGE);
+        assert_eq!(super::examples_of(articles[0]), ["1 and 2\n3 < 4\n"]);
+        assert_eq!(super::examples_of(articles[1]), ["one and 2\n"]);
+    }
+
+    #[test]
+    fn download_puzzle_into() {
+        let root = std::env::temp_dir().join(format!("aoc_puzzle_{}", std::process::id()));
+        let server = stand_in::serve(vec![(200, String::from(PAGE))]);
+        let downloaded =
+            super::download_puzzle_into(&root, &site(&server.url, Some("abc")), 2023, 1).unwrap();
+        let request = server.requests().remove(0);
+        assert!(request.starts_with("GET /2023/day/1 HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc"));
+
+        assert_eq!(downloaded.description, root.join("puzzles/day_01.md"));
+        assert!(fs::read_to_string(&downloaded.description)
+            .unwrap()
+            .starts_with("## --- Day 1: Example Puzzle ---"));
+        assert_eq!(
+            downloaded.examples,
+            [
+                root.join("input_test/day_01.txt"),
+                root.join("input_test/day_01/example_2.txt")
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("input_test/day_01/example_2.txt")).unwrap(),
+            "one and 2\n"
+        );
+
+        // existing examples are not overwritten
+        let server = stand_in::serve(vec![(200, String::from(PAGE))]);
+        let downloaded =
+            super::download_puzzle_into(&root, &site(&server.url, None), 2023, 1).unwrap();
+        assert!(downloaded.examples.is_empty());
+        assert!(!server.requests()[0].contains("Cookie"));
+
+        let server = stand_in::serve(vec![(404, String::from("Not found"))]);
+        assert!(matches!(
+            super::download_puzzle_into(&root, &site(&server.url, None), 2023, 2),
+            Err(SiteError::NotFound(_))
+        ));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,181 @@
+use curl::easy::Easy;
+use std::env;
+use std::fs;
+
+use super::SiteError;
+
+/// Address of the Advent of Code site
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// File with the value of `session` cookie of the logged in user
+pub const SESSION_FILE: &str = "./secrets/SESSION";
+
+/*
+Settings are taken from the first source that has them: environment variable, e.g. `AOC_BASE_URL`,
+then line `key = value`, e.g. `base_url = ...`, of the config file `./aoc.conf`.
+Settings of the site are `base_url`, `session` and `contact`,
+the session may also be kept in the session file `./secrets/SESSION`.
+Contact (e-mail or repository address) is added to the User-Agent header, as the site asks automated tools to do.
+*/
+pub const CONFIG_FILE: &str = "./aoc.conf";
+
+/// Value of the setting from environment variable `AOC_<NAME>` or from line `name = ...` of the config file
+pub fn setting(name: &str) -> Option<String> {
+    let config = fs::read_to_string(CONFIG_FILE).unwrap_or_default();
+    find_setting(|variable| env::var(variable).ok(), &config, name)
+}
+
+fn find_setting(
+    variable: impl Fn(&str) -> Option<String>,
+    config: &str,
+    name: &str,
+) -> Option<String> {
+    variable(&format!("AOC_{}", name.to_uppercase()))
+        .or_else(|| {
+            config
+                .lines()
+                .filter(|line| !line.trim_start().starts_with('#'))
+                .filter_map(|line| line.split_once('='))
+                .find(|(key, _)| key.trim() == name)
+                .map(|(_, value)| String::from(value))
+        })
+        .map(|value| String::from(value.trim()))
+        .filter(|value| !value.is_empty())
+}
+
+/// Where the site is and who is asking it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Site {
+    pub base_url: String,
+    /// Value of `session` cookie, pages that need no login are requested without it
+    pub session: Option<String>,
+    pub contact: Option<String>,
+}
+
+impl Default for Site {
+    fn default() -> Self {
+        Site {
+            base_url: String::from(BASE_URL),
+            session: None,
+            contact: None,
+        }
+    }
+}
+
+impl Site {
+    /// Settings from the environment, the config file and the session file
+    pub fn load() -> Site {
+        let config = fs::read_to_string(CONFIG_FILE).unwrap_or_default();
+        let session = fs::read_to_string(SESSION_FILE).ok();
+        Site::from_sources(|name| env::var(name).ok(), &config, session.as_deref())
+    }
+
+    fn from_sources(
+        variable: impl Fn(&str) -> Option<String>,
+        config: &str,
+        session_file: Option<&str>,
+    ) -> Site {
+        let setting = |name: &str| find_setting(&variable, config, name);
+        Site {
+            base_url: setting("base_url")
+                .map(|url| String::from(url.trim_end_matches('/')))
+                .unwrap_or_else(|| String::from(BASE_URL)),
+            session: setting("session").or_else(|| {
+                session_file
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(String::from)
+            }),
+            contact: setting("contact"),
+        }
+    }
+
+    pub fn user_agent(&self) -> String {
+        let name = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+        match &self.contact {
+            Some(contact) => format!("{name} ({contact})"),
+            None => String::from(name),
+        }
+    }
+
+    /// Session cookie, for the pages that are different for every user
+    pub fn session(&self) -> Result<&str, SiteError> {
+        self.session.as_deref().ok_or(SiteError::MissingSession)
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    /// Get body of the page, fail unless it is served with status 200
+    pub fn fetch(&self, path: &str) -> Result<String, SiteError> {
+        self.request(path, None)
+    }
+
+    /// Send form to the page, fail unless the response has status 200
+    pub fn post(&self, path: &str, form: &str) -> Result<String, SiteError> {
+        self.request(path, Some(form))
+    }
+
+    fn request(&self, path: &str, form: Option<&str>) -> Result<String, SiteError> {
+        let url = self.url(path);
+        let mut body = Vec::new();
+        let mut handle = Easy::new();
+        handle.url(&url)?;
+        handle.useragent(&self.user_agent())?;
+        if let Some(key) = &self.session {
+            handle.cookie(&format!("session={key}"))?;
+        }
+        if let Some(form) = form {
+            handle.post_fields_copy(form.as_bytes())?;
+        }
+        {
+            let mut transfer = handle.transfer();
+            transfer.write_function(|data| {
+                body.extend_from_slice(data);
+                Ok(data.len())
+            })?;
+            transfer.perform()?;
+        }
+        match handle.response_code()? {
+            200 => String::from_utf8(body).map_err(|_| SiteError::UnexpectedPage(url)),
+            // the site answers with "400 Bad Request" to a session it does not know
+            400 | 401 | 403 if self.session.is_some() => Err(SiteError::SessionRejected),
+            404 => Err(SiteError::NotFound(url)),
+            status => Err(SiteError::Status(url, status)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_sources() {
+        let config = "# settings\nbase_url = http://localhost:8080/\ncontact = me@example.com\n";
+        let no_variables = |_: &str| None;
+        assert_eq!(
+            Site::from_sources(no_variables, "", Some("abc\n")),
+            Site {
+                session: Some(String::from("abc")),
+                ..Site::default()
+            }
+        );
+        assert_eq!(
+            Site::from_sources(no_variables, config, None),
+            Site {
+                base_url: String::from("http://localhost:8080"),
+                session: None,
+                contact: Some(String::from("me@example.com")),
+            }
+        );
+        let variables = |name: &str| (name == "AOC_SESSION").then(|| String::from("xyz"));
+        let site = Site::from_sources(variables, "session = def", Some("abc"));
+        assert_eq!(site.session.as_deref(), Some("xyz"));
+        assert!(site.user_agent().starts_with("aoc2023_rust/"));
+        assert!(Site::from_sources(no_variables, config, None)
+            .user_agent()
+            .ends_with(" (me@example.com)"));
+    }
+}
@@ -0,0 +1,47 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+/// Local HTTP server standing in for the site in tests
+pub struct StandIn {
+    pub url: String,
+    handle: JoinHandle<Vec<String>>,
+}
+
+/// Serve given responses (status code and body) to the next requests, one response per request
+pub fn serve(responses: Vec<(u32, String)>) -> StandIn {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|(status, body)| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                while !request.ends_with("\r\n\r\n") && reader.read_line(&mut request).unwrap() > 0 {}
+                let length = request
+                    .lines()
+                    .find_map(|line| line.to_lowercase().strip_prefix("content-length:").map(|l| l.trim().parse().unwrap()))
+                    .unwrap_or(0);
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8_lossy(&content));
+                let response = format!(
+                    "HTTP/1.1 {status} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+                request
+            })
+            .collect()
+    });
+    StandIn { url, handle }
+}
+
+impl StandIn {
+    /// Requests received by the server, with headers and body
+    pub fn requests(self) -> Vec<String> {
+        self.handle.join().unwrap()
+    }
+}
@@ -0,0 +1,298 @@
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::{Site, SiteError};
+use crate::{year_folder, Day, Part, Year};
+
+/// Reply of the site to the submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Answer was not checked, next one may be submitted after the given time
+    RateLimited(Duration),
+    /// The part is already solved or part 1 is not solved yet
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Read the outcome from the text of the response page
+    pub fn parse(html: &str) -> Option<Outcome> {
+        if html.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if html.contains("That's not the right answer") {
+            Some(if html.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if html.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            })
+        } else if html.contains("You gave an answer too recently") {
+            let re_wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let seconds = re_wait.captures(html).map_or(0, |c| {
+                let number = |i| c.get(i).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+                number(1) * 60 + number(2)
+            });
+            Some(Outcome::RateLimited(Duration::from_secs(seconds)))
+        } else if html.contains("You don't seem to be solving the right level") {
+            Some(Outcome::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// The site has checked the answer, so there is no point in submitting it again
+    pub fn is_final(&self) -> bool {
+        !matches!(self, Outcome::RateLimited(_) | Outcome::WrongLevel)
+    }
+
+    fn code(&self) -> String {
+        match self {
+            Outcome::Correct => String::from("correct"),
+            Outcome::TooHigh => String::from("too-high"),
+            Outcome::TooLow => String::from("too-low"),
+            Outcome::Wrong => String::from("wrong"),
+            Outcome::RateLimited(wait) => format!("rate-limited:{}", wait.as_secs()),
+            Outcome::WrongLevel => String::from("wrong-level"),
+        }
+    }
+
+    fn from_code(code: &str) -> Option<Outcome> {
+        match code {
+            "correct" => Some(Outcome::Correct),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            "wrong-level" => Some(Outcome::WrongLevel),
+            code => code
+                .strip_prefix("rate-limited:")
+                .and_then(|s| s.parse().ok())
+                .map(|s| Outcome::RateLimited(Duration::from_secs(s))),
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited(wait) => {
+                write!(
+                    f,
+                    "not checked, wait {}s before next answer",
+                    wait.as_secs()
+                )
+            }
+            Outcome::WrongLevel => write!(f, "not checked, the part is already solved or locked"),
+        }
+    }
+}
+
+/*
+Every submission is recorded in `./submissions/YYYY/day_NN.log`, one line per answer:
+time of submission, part, answer and outcome, separated by tabs.
+The log prevents submitting the same wrong answer twice.
+*/
+fn log_path(root: &Path, year: Year, day: Day) -> PathBuf {
+    year_folder(&root.join("submissions"), year).join(format!("day_{:0>2}.log", day))
+}
+
+/// Outcome of the same answer submitted earlier, if the site has checked it
+pub fn previous_outcome(
+    root: &Path,
+    year: Year,
+    day: Day,
+    part: Part,
+    answer: &str,
+) -> Option<Outcome> {
+    let log = fs::read_to_string(log_path(root, year, day)).ok()?;
+    let part = part.number().to_string();
+    log.lines()
+        .filter_map(|line| match line.split('\t').collect::<Vec<_>>()[..] {
+            [_, p, a, outcome] if p == part && a == answer => Outcome::from_code(outcome),
+            _ => None,
+        })
+        .rfind(Outcome::is_final)
+}
+
+/// Time when the correct answer to the part was submitted
+pub fn solved_at(root: &Path, year: Year, day: Day, part: Part) -> Option<DateTime<Utc>> {
+    let log = fs::read_to_string(log_path(root, year, day)).ok()?;
+    let part = part.number().to_string();
+    log.lines()
+        .find_map(|line| match line.split('\t').collect::<Vec<_>>()[..] {
+            [time, p, _, "correct"] if p == part => DateTime::parse_from_rfc3339(time).ok(),
+            _ => None,
+        })
+        .map(|time| time.with_timezone(&Utc))
+}
+
+fn record(
+    root: &Path,
+    year: Year,
+    day: Day,
+    part: Part,
+    answer: &str,
+    outcome: &Outcome,
+) -> Result<(), SiteError> {
+    let path = log_path(root, year, day);
+    let line = format!(
+        "{}\t{}\t{}\t{}\n",
+        Utc::now().to_rfc3339(),
+        part.number(),
+        answer,
+        outcome.code()
+    );
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| OpenOptions::new().create(true).append(true).open(&path))
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .map_err(|e| SiteError::File(path, e))
+}
+
+fn url_encode(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                String::from(b as char)
+            }
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+pub fn submit(year: Year, day: Day, part: Part, answer: &str) -> Result<Outcome, SiteError> {
+    submit_to(Path::new("."), &Site::load(), year, day, part, answer)
+}
+
+/// Post the answer to the site and record the outcome under `root`
+pub fn submit_to(
+    root: &Path,
+    site: &Site,
+    year: Year,
+    day: Day,
+    part: Part,
+    answer: &str,
+) -> Result<Outcome, SiteError> {
+    site.session()?;
+    let path = format!("/{year}/day/{day}/answer");
+    let form = format!("level={}&answer={}", part.number(), url_encode(answer));
+    let response = site.post(&path, &form)?;
+    let outcome =
+        Outcome::parse(&response).ok_or_else(|| SiteError::UnexpectedPage(site.url(&path)))?;
+    record(root, year, day, part, answer, &outcome)?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::stand_in;
+
+    fn page(text: &str) -> String {
+        format!("<html><body><main><article><p>{text}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn parse() {
+        let parse = |text| Outcome::parse(&page(text));
+        assert_eq!(
+            parse("That's the right answer!  You are <span>one gold star</span> closer."),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too high.  Please wait."),
+            Some(Outcome::TooHigh)
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too low."),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            parse("That's not the right answer.  If you're stuck, ask for help."),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            parse("You gave an answer too recently.  You have 2m 13s left to wait."),
+            Some(Outcome::RateLimited(Duration::from_secs(133)))
+        );
+        assert_eq!(
+            parse("You gave an answer too recently.  You have 45s left to wait."),
+            Some(Outcome::RateLimited(Duration::from_secs(45)))
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(Outcome::WrongLevel)
+        );
+        assert_eq!(parse("Something else"), None);
+    }
+
+    #[test]
+    fn code() {
+        [
+            Outcome::Correct,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::Wrong,
+            Outcome::RateLimited(Duration::from_secs(61)),
+            Outcome::WrongLevel,
+        ]
+        .into_iter()
+        .for_each(|o| assert_eq!(Outcome::from_code(&o.code()), Some(o)));
+    }
+
+    #[test]
+    fn submit_to() {
+        let root = std::env::temp_dir().join(format!("aoc_submit_{}", std::process::id()));
+        let server = stand_in::serve(vec![
+            (
+                200,
+                page("You gave an answer too recently.  You have 30s left to wait."),
+            ),
+            (
+                200,
+                page("That's not the right answer; your answer is too low."),
+            ),
+            (200, page("That's the right answer!")),
+        ]);
+        let site = Site {
+            base_url: server.url.clone(),
+            session: Some(String::from("abc")),
+            ..Site::default()
+        };
+        let submit = |answer| super::submit_to(&root, &site, 2023, 5, Part::Two, answer);
+        assert_eq!(
+            submit("1 2").unwrap(),
+            Outcome::RateLimited(Duration::from_secs(30))
+        );
+        assert_eq!(previous_outcome(&root, 2023, 5, Part::Two, "1 2"), None);
+        assert_eq!(submit("1 2").unwrap(), Outcome::TooLow);
+        assert_eq!(
+            previous_outcome(&root, 2023, 5, Part::Two, "1 2"),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(previous_outcome(&root, 2023, 5, Part::One, "1 2"), None);
+        assert_eq!(solved_at(&root, 2023, 5, Part::Two), None);
+        assert_eq!(submit("3 4").unwrap(), Outcome::Correct);
+        assert!(solved_at(&root, 2023, 5, Part::Two).is_some_and(|time| time <= Utc::now()));
+
+        let requests = server.requests();
+        assert!(requests[1].starts_with("POST /2023/day/5/answer HTTP/1.1"));
+        assert!(requests[1].contains("Cookie: session=abc"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=2&answer=1%202"));
+        let log = fs::read_to_string(root.join("submissions/day_05.log")).unwrap();
+        assert_eq!(log.lines().count(), 3);
+        fs::remove_dir_all(root).unwrap();
+    }
+}